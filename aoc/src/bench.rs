@@ -0,0 +1,175 @@
+use crate::solutions;
+use anyhow::Result;
+use common::input;
+use serde::Serialize;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+}
+
+impl Stats {
+    fn of(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let nth = |quantile: f64| {
+            let index = (samples.len() as f64 * quantile).ceil() as usize;
+            samples[index.clamp(1, samples.len()) - 1].as_nanos()
+        };
+
+        Stats {
+            min_ns: samples[0].as_nanos(),
+            median_ns: nth(0.5),
+            p95_ns: nth(0.95),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Measurement {
+    day: u8,
+    phase: &'static str,
+    iterations: usize,
+    #[serde(flatten)]
+    stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+// times `f` `iterations` times, stops at the first error
+fn measure<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Stats)> {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    Ok((result.unwrap(), Stats::of(samples)))
+}
+
+fn measurement<T>(
+    day: u8,
+    phase: &'static str,
+    iterations: usize,
+    result: &Result<(T, Stats)>,
+) -> Measurement {
+    let (stats, error) = match result {
+        Ok((_, stats)) => (Some(stats.clone()), None),
+        Err(e) => (None, Some(format!("{e:#}"))),
+    };
+
+    Measurement {
+        day,
+        phase,
+        iterations,
+        stats,
+        error,
+    }
+}
+
+fn bench_day(day: u8, iterations: usize, cache_dir: Option<&Path>) -> Vec<Measurement> {
+    // a day without input is an error row, the other days are still benchmarked
+    let input = match input::load(day, None, cache_dir) {
+        Ok((_, input)) => input,
+        Err(e) => return vec![measurement::<()>(day, "input", iterations, &Err(e))],
+    };
+    let solution = solutions::solution(day).unwrap();
+
+    let parsed = measure(iterations, || solution.parse(&input));
+    let mut measurements = vec![measurement(day, "parse", iterations, &parsed)];
+
+    if let Ok((parsed, _)) = parsed {
+        for (phase, part) in [("part1", 1), ("part2", 2)] {
+            let result = measure(iterations, || match part {
+                1 => solution.part1(parsed.as_ref()),
+                _ => solution.part2(parsed.as_ref()),
+            });
+            measurements.push(measurement(day, phase, iterations, &result));
+        }
+    }
+
+    measurements
+}
+
+pub fn bench(days: Vec<u8>, iterations: usize, json: bool, cache_dir: Option<&Path>) -> Result<()> {
+    let mut measurements = vec![];
+    for day in days {
+        measurements.extend(bench_day(day, iterations, cache_dir));
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&measurements)?);
+        return Ok(());
+    }
+
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+        "day", "phase", "min", "median", "p95"
+    );
+    for Measurement {
+        day,
+        phase,
+        stats,
+        error,
+        ..
+    } in measurements
+    {
+        match (stats, error) {
+            (Some(stats), _) => println!(
+                "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                day,
+                phase,
+                format!("{:?}", Duration::from_nanos(stats.min_ns as u64)),
+                format!("{:?}", Duration::from_nanos(stats.median_ns as u64)),
+                format!("{:?}", Duration::from_nanos(stats.p95_ns as u64)),
+            ),
+            (None, error) => println!(
+                "{:>3}  {:<6}  error: {}",
+                day,
+                phase,
+                error.unwrap_or_default()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn stats() {
+        let samples = (1..=100).rev().map(Duration::from_nanos).collect();
+
+        assert_eq!(
+            Stats::of(samples),
+            Stats {
+                min_ns: 1,
+                median_ns: 50,
+                p95_ns: 95
+            }
+        );
+    }
+
+    #[test]
+    fn stats_of_single_sample() {
+        assert_eq!(
+            Stats::of(vec![Duration::from_nanos(7)]),
+            Stats {
+                min_ns: 7,
+                median_ns: 7,
+                p95_ns: 7
+            }
+        );
+    }
+}
@@ -0,0 +1,200 @@
+mod bench;
+mod verify;
+
+use anyhow::{bail, Context, Result};
+use aoc::solutions;
+use clap::builder::RangedU64ValueParser;
+use clap::{Parser, Subcommand};
+use common::cli::LoggingArgs;
+use common::error::annotate;
+use common::input::{self, CACHE_DIR_ENV};
+use common::store::{Entry, Store};
+use log::debug;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(about = "Runs the advent of code 2022 solutions")]
+struct Cli {
+    /// Directory of the input store [default: $XDG_CACHE_HOME/aoc or ~/.cache/aoc]
+    #[arg(long, global = true, env = CACHE_DIR_ENV)]
+    cache_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    logging: LoggingArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day
+    Run {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=8))]
+        day: u8,
+        /// Part to run, both parts are run if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, '-' reads from stdin [default: looked up in $AOC_INPUT_DIR, the input store and the workspace]
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Run all days and compare the results with the answers recorded in 'day<N>/answers.toml'
+    Verify {
+        /// Directory with the 'day<N>/answers.toml' files [default: the workspace the runner was built in]
+        #[arg(long, value_name = "DIR")]
+        answers_dir: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of every day separately
+    Bench {
+        /// Day to benchmark, all days are benchmarked if omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=8))]
+        day: Option<u8>,
+        /// Number of timed runs per phase
+        #[arg(short = 'n', long, default_value_t = 100, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        iterations: usize,
+        /// Print the measurements as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Manage the input store
+    #[command(subcommand)]
+    Store(StoreCommand),
+}
+
+#[derive(Subcommand)]
+enum StoreCommand {
+    /// Add inputs to the store, all 'day<N>/input' files of the workspace if no day is given
+    Add {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=8))]
+        day: Option<u8>,
+        /// Input file to store [default: 'day<DAY>/input']
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Check the stored inputs and the workspace inputs against the manifest
+    Verify,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    cli.logging.init();
+
+    let cache_dir = cli.cache_dir.or_else(input::default_cache_dir);
+
+    match cli.command {
+        Command::Run { day, part, input } => run(day, part, input, cache_dir.as_deref()),
+        Command::Verify { answers_dir } => {
+            verify::verify(answers_dir.as_deref(), cache_dir.as_deref())
+        }
+        Command::Bench {
+            day,
+            iterations,
+            json,
+        } => {
+            let days = match day {
+                Some(day) => vec![day],
+                None => solutions::DAYS.collect(),
+            };
+            bench::bench(days, iterations, json, cache_dir.as_deref())
+        }
+        Command::Store(command) => {
+            let mut store = Store::open(cache_dir.context("no cache directory configured")?)?;
+
+            match command {
+                StoreCommand::Add { day, input } => store_add(&mut store, day, input),
+                StoreCommand::Verify => store_verify(&store),
+            }
+        }
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<String>, cache_dir: Option<&Path>) -> Result<()> {
+    let (source, content) = input::load(day, input.as_deref(), cache_dir)?;
+    let solution = solutions::solution(day).context(format!("no solution for day {day}"))?;
+    debug!("running day {day} on '{source}'");
+
+    let start = Instant::now();
+    let input = solution
+        .parse(&content)
+        .map_err(|e| annotate(e, &content))
+        .context(format!("while parsing '{}'", source))?;
+    println!("day{day} parse: {:?}", start.elapsed());
+    for warning in solution.warnings(input.as_ref()) {
+        eprintln!("{source}: {warning}");
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let result = match part {
+            1 => solution.part1(input.as_ref()),
+            _ => solution.part2(input.as_ref()),
+        }
+        .map_err(|e| annotate(e, &content))
+        .context(format!("while running day {day} part {part}"))?;
+
+        println!("day{day} part{part}: {result} ({:?})", start.elapsed());
+    }
+
+    Ok(())
+}
+
+fn store_add(store: &mut Store, day: Option<u8>, input: Option<PathBuf>) -> Result<()> {
+    let inputs = match (day, input) {
+        (Some(day), Some(input)) => vec![(day, input)],
+        (Some(day), None) => vec![(day, workspace_input(day))],
+        (None, _) => solutions::DAYS
+            .map(|day| (day, workspace_input(day)))
+            .filter(|(_, input)| input.is_file())
+            .collect(),
+    };
+
+    for (day, input) in inputs {
+        let entry = store.add(day, &input::read(&input)?)?;
+        println!("day{day}: stored '{}' as {entry}", input.display());
+    }
+
+    Ok(())
+}
+
+fn store_verify(store: &Store) -> Result<()> {
+    let mut failures = 0;
+
+    for (day, entry) in store.entries() {
+        let object_path = store.object_path(day).unwrap();
+        let mut checked = vec![object_path];
+        checked.extend(Some(workspace_input(day)).filter(|path| path.is_file()));
+
+        for path in checked {
+            let actual = Entry::of(&std::fs::read(&path).unwrap_or_default());
+            if actual == *entry {
+                println!("day{day}: ok       {}", path.display());
+            } else {
+                println!(
+                    "day{day}: mismatch {}\n         expected {entry}, got {actual}",
+                    path.display()
+                );
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!(
+            "{failures} input(s) do not match the manifest in '{}'",
+            store.root().display()
+        )
+    }
+
+    Ok(())
+}
+
+fn workspace_input(day: u8) -> PathBuf {
+    Path::new(&format!("day{day}")).join("input")
+}
@@ -0,0 +1,73 @@
+use anyhow::{Context, Result};
+use common::Solution;
+use std::any::Any;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+pub const DAYS: RangeInclusive<u8> = 1..=8;
+
+// object safe counterpart of `common::Solution` so all days fit into one table
+pub trait AnySolution {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn part1(&self, input: &dyn Any) -> Result<String>;
+    fn part2(&self, input: &dyn Any) -> Result<String>;
+    fn warnings(&self, input: &dyn Any) -> Vec<String>;
+}
+
+struct Erased<S>(PhantomData<S>);
+
+impl<S> Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn downcast(input: &dyn Any) -> Result<&S::Input> {
+        input
+            .downcast_ref::<S::Input>()
+            .context("input was parsed by a different solution")
+    }
+}
+
+impl<S> AnySolution for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String> {
+        Ok(S::part1(Self::downcast(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<String> {
+        Ok(S::part2(Self::downcast(input)?)?.to_string())
+    }
+
+    fn warnings(&self, input: &dyn Any) -> Vec<String> {
+        Self::downcast(input).map(S::warnings).unwrap_or_default()
+    }
+}
+
+fn erase<S>() -> Box<dyn AnySolution>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    Box::new(Erased::<S>(PhantomData))
+}
+
+pub fn solution(day: u8) -> Option<Box<dyn AnySolution>> {
+    match day {
+        1 => Some(erase::<day1::Day1>()),
+        2 => Some(erase::<day2::Day2>()),
+        3 => Some(erase::<day3::Day3>()),
+        4 => Some(erase::<day4::Day4>()),
+        5 => Some(erase::<day5::Day5>()),
+        6 => Some(erase::<day6::Day6>()),
+        7 => Some(erase::<day7::Day7>()),
+        8 => Some(erase::<day8::Day8>()),
+        _ => None,
+    }
+}
@@ -0,0 +1,162 @@
+use crate::solutions;
+use anyhow::{anyhow, bail, Result};
+use common::answers::Answers;
+use common::input;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+enum Status {
+    Pass,
+    Fail,
+    Unrecorded,
+    Error(String),
+}
+
+struct Row {
+    day: u8,
+    part: Option<u8>,
+    expected: Option<String>,
+    actual: Option<String>,
+    status: Status,
+    time: Duration,
+}
+
+impl Row {
+    fn error(day: u8, part: Option<u8>, error: anyhow::Error, time: Duration) -> Self {
+        Row {
+            day,
+            part,
+            expected: None,
+            actual: None,
+            status: Status::Error(format!("{error:#}")),
+            time,
+        }
+    }
+}
+
+// runs every day against its input and compares the results with 'day<N>/answers.toml'
+// in `answers_dir`, the workspace the runner was built in by default
+pub fn verify(answers_dir: Option<&Path>, cache_dir: Option<&Path>) -> Result<()> {
+    let answers_dir = match answers_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+    };
+    let mut rows = vec![];
+
+    for day in solutions::DAYS {
+        rows.extend(verify_day(day, &answers_dir, cache_dir));
+    }
+
+    print_table(&rows);
+
+    let failures = rows
+        .iter()
+        .filter(|row| matches!(row.status, Status::Fail | Status::Error(_)))
+        .count();
+    if failures > 0 {
+        bail!("{failures} of {} checks failed", rows.len())
+    }
+    if !rows
+        .iter()
+        .any(|row| matches!(row.status, Status::Pass | Status::Fail))
+    {
+        bail!("no answer was compared")
+    }
+
+    Ok(())
+}
+
+fn verify_day(day: u8, answers_dir: &Path, cache_dir: Option<&Path>) -> Vec<Row> {
+    let path: PathBuf = [
+        answers_dir,
+        Path::new(&format!("day{day}")),
+        Path::new("answers.toml"),
+    ]
+    .iter()
+    .collect();
+    let answers = match Answers::load(&path) {
+        Ok(answers) if answers == Answers::default() => {
+            let error = anyhow!("'{}' records no answer", path.display());
+            return vec![Row::error(day, None, error, Duration::ZERO)];
+        }
+        Ok(answers) => answers,
+        Err(e) => return vec![Row::error(day, None, e, Duration::ZERO)],
+    };
+    let (source, input) = match input::load(day, None, cache_dir) {
+        Ok(input) => input,
+        Err(e) => return vec![Row::error(day, None, e, Duration::ZERO)],
+    };
+    let solution = solutions::solution(day).unwrap();
+
+    let start = Instant::now();
+    let input = match solution.parse(&input) {
+        Ok(input) => input,
+        Err(e) => return vec![Row::error(day, None, e, start.elapsed())],
+    };
+    for warning in solution.warnings(input.as_ref()) {
+        eprintln!("{source}: {warning}");
+    }
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let result = match part {
+                1 => solution.part1(input.as_ref()),
+                _ => solution.part2(input.as_ref()),
+            };
+            let time = start.elapsed();
+            let expected = answers.part(part).map(String::from);
+
+            match (result, expected) {
+                (Ok(actual), expected) => Row {
+                    day,
+                    part: Some(part),
+                    status: match &expected {
+                        Some(expected) if *expected == actual => Status::Pass,
+                        Some(_) => Status::Fail,
+                        None => Status::Unrecorded,
+                    },
+                    expected,
+                    actual: Some(actual),
+                    time,
+                },
+                // parts without a recorded answer are allowed to be unsolved
+                (Err(e), None) => Row {
+                    status: Status::Unrecorded,
+                    ..Row::error(day, Some(part), e, time)
+                },
+                (Err(e), expected) => Row {
+                    expected,
+                    ..Row::error(day, Some(part), e, time)
+                },
+            }
+        })
+        .collect()
+}
+
+fn print_table(rows: &[Row]) {
+    println!(
+        "{:>3}  {:>4}  {:<12}  {:<12}  {:<10}  {:>12}",
+        "day", "part", "expected", "actual", "result", "time"
+    );
+
+    for row in rows {
+        let status = match &row.status {
+            Status::Pass => "pass".to_string(),
+            Status::Fail => "FAIL".to_string(),
+            Status::Unrecorded => "unrecorded".to_string(),
+            Status::Error(e) => format!("ERROR: {e}"),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {:<12}  {:<12}  {:<10}  {:>12}",
+            row.day,
+            row.part.map(|part| part.to_string()).unwrap_or_default(),
+            row.expected.as_deref().unwrap_or("-"),
+            row.actual.as_deref().unwrap_or("-"),
+            status,
+            format!("{:?}", row.time)
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// runs every 'fixtures/day<N>/<name>.txt' through the solution of day N and compares
+// the results with the answers recorded next to it in '<name>.toml'
+use anyhow::{bail, Context, Result};
+use aoc::solutions;
+use common::answers::Answers;
+use common::error::annotate;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct Fixture {
+    day: u8,
+    input: PathBuf,
+    answers: Answers,
+}
+
+fn discover(root: &Path) -> Result<Vec<Fixture>> {
+    let mut fixtures = vec![];
+
+    for dir in fs::read_dir(root).context(format!("while reading '{}'", root.display()))? {
+        let dir = dir?.path();
+        let day = dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u8>().ok())
+            .context(format!("'{}' is not a 'day<N>' directory", dir.display()))?;
+
+        let mut inputs = fs::read_dir(&dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        inputs.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+        inputs.sort();
+
+        for input in inputs {
+            let expected = input.with_extension("toml");
+            if !expected.is_file() {
+                bail!(
+                    "fixture '{}' has no '{}'",
+                    input.display(),
+                    expected.display()
+                )
+            }
+
+            let answers = Answers::load(&expected)?;
+            if answers == Answers::default() {
+                bail!("'{}' records no answer", expected.display())
+            }
+
+            fixtures.push(Fixture {
+                day,
+                input,
+                answers,
+            });
+        }
+    }
+
+    fixtures.sort_by(|a, b| (a.day, &a.input).cmp(&(b.day, &b.input)));
+    Ok(fixtures)
+}
+
+fn check(fixture: &Fixture) -> Result<()> {
+    let content = fs::read_to_string(&fixture.input)?;
+    let solution =
+        solutions::solution(fixture.day).context(format!("no solution for day {}", fixture.day))?;
+
+    let input = solution
+        .parse(&content)
+        .map_err(|e| annotate(e, &content))
+        .context("while parsing")?;
+
+    for part in [1, 2] {
+        let Some(expected) = fixture.answers.part(part) else {
+            continue;
+        };
+
+        let actual = match part {
+            1 => solution.part1(input.as_ref()),
+            _ => solution.part2(input.as_ref()),
+        }
+        .map_err(|e| annotate(e, &content))
+        .context(format!("while running part {part}"))?;
+
+        if actual != expected {
+            bail!("part {part}: expected {expected}, got {actual}")
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn fixtures() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+    let fixtures = discover(&root).unwrap();
+    assert!(!fixtures.is_empty(), "no fixtures in '{}'", root.display());
+
+    let failures: Vec<String> = fixtures
+        .iter()
+        .filter_map(|fixture| {
+            check(fixture)
+                .err()
+                .map(|e| format!("{}: {e:#}", fixture.input.display()))
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} fixtures failed:\n{}",
+        failures.len(),
+        fixtures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,52 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+// the accepted answers of a day as recorded in its 'answers.toml'
+#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let answers =
+            fs::read_to_string(path).context(format!("while opening file '{}'", path.display()))?;
+        toml::from_str(&answers).context(format!("while parsing file '{}'", path.display()))
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn parse() {
+        let answers: Answers = toml::from_str("part1 = \"BZLVHBWQF\"\n").unwrap();
+
+        assert_eq!(answers.part(1), Some("BZLVHBWQF"));
+        assert_eq!(answers.part(2), None);
+        assert_eq!(answers.part(3), None);
+    }
+
+    #[test]
+    fn missing_file() {
+        assert_eq!(
+            Answers::load("nonexisting/answers.toml")
+                .unwrap_err()
+                .to_string(),
+            "while opening file 'nonexisting/answers.toml'"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::input::{self, Source, CACHE_DIR_ENV};
+use crate::logging::{self, LogFormat};
+use anyhow::Result;
+use clap::Parser;
+use std::io::BufRead;
+use std::path::PathBuf;
+
+// logging options of the runner and all day binaries
+#[derive(clap::Args, Debug)]
+pub struct LoggingArgs {
+    /// Log debug events, twice to also log trace events
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Format of the log events on stderr
+    #[arg(long, value_enum, default_value_t = LogFormat::Pretty, global = true)]
+    pub log_format: LogFormat,
+}
+
+impl LoggingArgs {
+    pub fn init(&self) {
+        logging::init(self.verbose, self.log_format)
+    }
+}
+
+// command line arguments shared by all day binaries
+#[derive(Parser, Debug)]
+pub struct Args {
+    /// Puzzle input file, '-' reads from stdin [default: looked up in $AOC_INPUT_DIR, the input store and the current directory]
+    pub input: Option<String>,
+
+    /// Directory of the input store [default: $XDG_CACHE_HOME/aoc or ~/.cache/aoc]
+    #[arg(long, env = CACHE_DIR_ENV)]
+    pub cache_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub logging: LoggingArgs,
+}
+
+impl Args {
+    pub fn load(&self, day: u8) -> Result<(Source, String)> {
+        input::load(day, self.input.as_deref(), self.cache_dir.as_deref())
+    }
+
+    pub fn open(&self, day: u8) -> Result<(Source, Box<dyn BufRead>)> {
+        input::open(day, self.input.as_deref(), self.cache_dir.as_deref())
+    }
+}
@@ -0,0 +1,230 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+// position of some offending text in the input, lines and columns start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+// a line of the input together with its number
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line { number, text }
+    }
+
+    // numbered lines of `input`
+    pub fn all(input: &'a str) -> impl Iterator<Item = Line<'a>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, text)| Line::new(index + 1, text))
+    }
+
+    // span of `part` which has to be a sub slice of this line, otherwise the
+    // span points to the start of the line
+    pub fn span(&self, part: &str) -> Span {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = match offset <= self.text.len() {
+            true => self.text[..offset].chars().count() + 1,
+            false => 1,
+        };
+
+        Span {
+            line: self.number,
+            column,
+            text: part.to_string(),
+        }
+    }
+
+    pub fn whole(&self) -> Span {
+        self.span(self.text)
+    }
+}
+
+// implemented by errors that know where in the input they occurred
+pub trait Spanned {
+    fn span(&self) -> &Span;
+}
+
+// Keeps the span of an error of a day crate, so that the runner can find it in an
+// `anyhow::Error` and show the offending input.
+pub struct Diagnostic {
+    span: Span,
+    error: Box<dyn Error + Send + Sync>,
+}
+
+impl<E> From<E> for Diagnostic
+where
+    E: Spanned + Error + Send + Sync + 'static,
+{
+    fn from(error: E) -> Self {
+        Diagnostic {
+            span: error.span().clone(),
+            error: Box::new(error),
+        }
+    }
+}
+
+impl Diagnostic {
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    // the offending line of `input` with carets below the offending text:
+    //
+    //   in line 3, column 3:
+    //   3 | 2-x4,6-8
+    //     |   ^^
+    pub fn snippet(&self, input: &str) -> String {
+        let Span { line, column, text } = &self.span;
+        let source_line = input
+            .lines()
+            .nth(line.saturating_sub(1))
+            .unwrap_or_default();
+
+        format!(
+            "in {}:\n{line} | {source_line}\n{} | {}{}",
+            self.span,
+            " ".repeat(line.to_string().len()),
+            " ".repeat(column.saturating_sub(1)),
+            "^".repeat(text.chars().count().max(1))
+        )
+    }
+}
+
+impl Debug for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.error, f)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl Error for Diagnostic {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+// adds the offending input as context if `error` contains a `Diagnostic`
+pub fn annotate(error: anyhow::Error, input: &str) -> anyhow::Error {
+    match error.downcast_ref::<Diagnostic>() {
+        Some(diagnostic) => {
+            let snippet = diagnostic.snippet(input);
+            error.context(snippet)
+        }
+        None => error,
+    }
+}
+
+// like `annotate`, but only adds the location, for inputs that aren't kept in memory
+pub fn locate(error: anyhow::Error) -> anyhow::Error {
+    match error.downcast_ref::<Diagnostic>() {
+        Some(diagnostic) => {
+            let location = format!("in {}", diagnostic.span());
+            error.context(location)
+        }
+        None => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Diagnostic, Line, Span, Spanned};
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    #[error("invalid section id '{}'", .0.text)]
+    struct InvalidSection(Span);
+
+    impl Spanned for InvalidSection {
+        fn span(&self) -> &Span {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn span_of_sub_slice() {
+        let line = Line::new(3, "2-x4,6-8");
+        let (range, _) = line.text.split_once(',').unwrap();
+
+        assert_eq!(
+            line.span(&range[2..]),
+            Span {
+                line: 3,
+                column: 3,
+                text: "x4".into()
+            }
+        );
+        assert_eq!(line.span("not a sub slice").column, 1);
+    }
+
+    #[test]
+    fn snippet() {
+        let line = Line::new(3, "2-x4,6-8");
+        let diagnostic = Diagnostic::from(InvalidSection(line.span(&line.text[2..4])));
+
+        assert_eq!(diagnostic.to_string(), "invalid section id 'x4'");
+        assert_eq!(
+            diagnostic.snippet("2-4,6-8\n2-3,4-5\n2-x4,6-8\n"),
+            "in line 3, column 3:
+3 | 2-x4,6-8
+  |   ^^"
+        );
+    }
+
+    #[test]
+    fn annotate() {
+        let line = Line::new(1, "2-x4,6-8");
+        let error = anyhow::Error::from(Diagnostic::from(InvalidSection(
+            line.span(&line.text[2..4]),
+        )));
+
+        let annotated = super::annotate(error, line.text);
+        assert_eq!(
+            annotated.chain().map(|e| e.to_string()).collect::<Vec<_>>(),
+            vec![
+                "in line 1, column 3:\n1 | 2-x4,6-8\n  |   ^^".to_string(),
+                "invalid section id 'x4'".to_string(),
+            ]
+        );
+        assert!(super::annotate(anyhow::anyhow!("no span"), line.text)
+            .downcast_ref::<Diagnostic>()
+            .is_none());
+    }
+
+    #[test]
+    fn locate() {
+        let line = Line::new(4, "2-x4,6-8");
+        let error = anyhow::Error::from(Diagnostic::from(InvalidSection(
+            line.span(&line.text[2..4]),
+        )));
+
+        assert_eq!(
+            super::locate(error)
+                .chain()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            vec!["in line 4, column 3", "invalid section id 'x4'"]
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use crate::store::Store;
+use anyhow::{bail, Context, Result};
+use log::{debug, trace};
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl Source {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("while reading from stdin")?;
+                Ok(input)
+            }
+            Source::File(path) => read(path),
+        }
+    }
+
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => {
+                let file = fs::File::open(path)
+                    .context(format!("while opening file '{}'", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+// an input given on the command line, "-" being stdin
+fn explicit(input: &str) -> Source {
+    match input {
+        "-" => Source::Stdin,
+        file_name => Source::File(file_name.into()),
+    }
+}
+
+pub fn read(file_name: impl AsRef<Path>) -> Result<String> {
+    let file_name = file_name.as_ref();
+    fs::read_to_string(file_name).context(format!("while opening file '{}'", file_name.display()))
+}
+
+// $AOC_CACHE_DIR, $XDG_CACHE_HOME/aoc or ~/.cache/aoc -- in that order
+pub fn default_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(CACHE_DIR_ENV) {
+        return Some(dir.into());
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Some(Path::new(&dir).join("aoc"));
+    }
+
+    env::var_os("HOME").map(|home| Path::new(&home).join(".cache").join("aoc"))
+}
+
+// all locations an input for `day` is looked up at, together with a description
+// of where the location came from
+fn candidates(
+    day: u8,
+    input_dir: Option<&Path>,
+    store: Option<&Store>,
+) -> Vec<(PathBuf, &'static str)> {
+    let day_input = Path::new(&format!("day{day}")).join("input");
+    let mut candidates = vec![];
+
+    if let Some(input_dir) = input_dir {
+        candidates.push((input_dir.join(&day_input), INPUT_DIR_ENV));
+    }
+    if let Some(object_path) = store.and_then(|store| store.object_path(day)) {
+        candidates.push((object_path, "input store"));
+    }
+    if let Some(store) = store {
+        candidates.push((store.root().join(&day_input), "cache directory"));
+    }
+    candidates.push((day_input, "workspace directory"));
+    candidates.push((PathBuf::from("input"), "current directory"));
+
+    candidates
+}
+
+fn find(candidates: Vec<(PathBuf, &'static str)>, day: u8) -> Result<Source> {
+    for (path, origin) in &candidates {
+        if path.is_file() {
+            debug!("using input '{}' ({origin})", path.display());
+            return Ok(Source::File(path.clone()));
+        }
+        trace!("no input at '{}' ({origin})", path.display());
+    }
+
+    let tried = candidates
+        .iter()
+        .map(|(path, origin)| format!("  {} ({origin})", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    bail!("no input found for day {day}, tried:\n{tried}")
+}
+
+// the first existing location of `candidates` for `day`, together with the input
+// store in `cache_dir` it has to match
+fn look_up(day: u8, cache_dir: Option<&Path>) -> Result<(Source, Option<Store>)> {
+    let input_dir = env::var_os(INPUT_DIR_ENV).map(PathBuf::from);
+    let store = match cache_dir.map(Path::to_path_buf).or_else(default_cache_dir) {
+        Some(cache_dir) => Some(Store::open(cache_dir)?),
+        None => None,
+    };
+
+    let source = find(candidates(day, input_dir.as_deref(), store.as_ref()), day)?;
+    Ok((source, store))
+}
+
+// An explicit `input` ("-" being stdin) is read as is. Otherwise the first existing
+// location of `candidates` is used and its content has to match the manifest of the
+// input store in `cache_dir`.
+pub fn load(day: u8, input: Option<&str>, cache_dir: Option<&Path>) -> Result<(Source, String)> {
+    let Some(input) = input else {
+        let (source, store) = look_up(day, cache_dir)?;
+        let content = source.read()?;
+        if let Some(store) = store {
+            store
+                .verify(day, &source.to_string(), &content)
+                .context(format!("refusing to use the input of day {day}"))?;
+        }
+
+        return Ok((source, content));
+    };
+
+    let source = explicit(input);
+    let content = source.read()?;
+    Ok((source, content))
+}
+
+// Like `load`, but the input is streamed instead of being read into memory. Looked up
+// inputs are verified while they are read, reading fails at their end if they don't
+// match the manifest.
+pub fn open(
+    day: u8,
+    input: Option<&str>,
+    cache_dir: Option<&Path>,
+) -> Result<(Source, Box<dyn BufRead>)> {
+    let Some(input) = input else {
+        let (source, store) = look_up(day, cache_dir)?;
+        let reader = match (&source, store) {
+            (Source::File(path), Some(store)) => {
+                let file = fs::File::open(path)
+                    .context(format!("while opening file '{}'", path.display()))?;
+                let reader = store.verifying(day, &source.to_string(), file);
+                Box::new(BufReader::new(reader))
+            }
+            _ => source.open()?,
+        };
+
+        return Ok((source, reader));
+    };
+
+    let source = explicit(input);
+    let reader = source.open()?;
+    Ok((source, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{candidates, find, load, open, Source};
+    use crate::store::Store;
+    use std::fs;
+    use std::io::BufRead;
+    use std::path::Path;
+
+    #[test]
+    fn explicit_input() {
+        let (source, content) = load(1, Some("Cargo.toml"), None).unwrap();
+
+        assert_eq!(source, Source::File("Cargo.toml".into()));
+        assert!(content.starts_with("[package]"));
+    }
+
+    #[test]
+    fn explicit_input_is_streamed() {
+        let (source, reader) = open(1, Some("Cargo.toml"), None).unwrap();
+
+        assert_eq!(source, Source::File("Cargo.toml".into()));
+        assert_eq!(reader.lines().next().unwrap().unwrap(), "[package]");
+    }
+
+    #[test]
+    fn candidate_order() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-order-{}", std::process::id()));
+        let mut store = Store::open(&dir).unwrap();
+        let entry = store.add(7, "$ cd /\n").unwrap();
+
+        let candidates = candidates(7, Some(Path::new("/inputs")), Some(&store));
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                Path::new("/inputs/day7/input").to_path_buf(),
+                dir.join("objects").join(entry.sha256),
+                dir.join("day7").join("input"),
+                Path::new("day7/input").to_path_buf(),
+                Path::new("input").to_path_buf(),
+            ]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn first_existing_candidate() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-find-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day3")).unwrap();
+        fs::write(dir.join("day3").join("input"), "vJrwpWtwJgWrhcsFMMfFFhFp\n").unwrap();
+
+        let source = find(candidates(3, Some(&dir), None), 3);
+        assert_eq!(
+            source.unwrap(),
+            Source::File(dir.join("day3").join("input"))
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn lists_all_tried_locations() {
+        let store = Store::open("/nonexisting/cache").unwrap();
+        let candidates = candidates(42, Some(Path::new("/nonexisting")), Some(&store));
+        let err = find(candidates, 42).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "no input found for day 42, tried:
+  /nonexisting/day42/input (AOC_INPUT_DIR)
+  /nonexisting/cache/day42/input (cache directory)
+  day42/input (workspace directory)
+  input (current directory)"
+        );
+    }
+
+    #[test]
+    fn plain_file_in_cache_dir_is_verified() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-cache-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day5")).unwrap();
+        fs::write(dir.join("day5").join("input"), "plain\n").unwrap();
+
+        let (source, content) = load(5, None, Some(&dir)).unwrap();
+        assert_eq!(source, Source::File(dir.join("day5").join("input")));
+        assert_eq!(content, "plain\n");
+
+        // once the store knows the input of the day, the file has to match it
+        let mut store = Store::open(&dir).unwrap();
+        store.add(5, "recorded\n").unwrap();
+        fs::remove_file(store.object_path(5).unwrap()).unwrap();
+        assert!(load(5, None, Some(&dir)).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn looked_up_input_is_verified_while_streaming() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-stream-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day6")).unwrap();
+        fs::write(dir.join("day6").join("input"), "mjqjpqm\n").unwrap();
+        let lines = || -> std::io::Result<Vec<String>> {
+            let (_, reader) = open(6, None, Some(&dir)).unwrap();
+            reader.lines().collect()
+        };
+
+        assert_eq!(lines().unwrap(), vec!["mjqjpqm"]);
+
+        let mut store = Store::open(&dir).unwrap();
+        store.add(6, "bvwbjplbgvbhsrlpgdmjqwftvncz\n").unwrap();
+        fs::remove_file(store.object_path(6).unwrap()).unwrap();
+        assert!(lines()
+            .unwrap_err()
+            .to_string()
+            .contains("does not match the manifest of day 6"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
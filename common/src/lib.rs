@@ -0,0 +1,10 @@
+pub mod answers;
+pub mod cli;
+pub mod error;
+pub mod input;
+pub mod logging;
+pub mod random;
+pub mod solution;
+pub mod store;
+
+pub use solution::{run, Solution};
@@ -0,0 +1,44 @@
+use clap::ValueEnum;
+use log::LevelFilter;
+use std::env;
+use std::io::Write;
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    #[default]
+    Pretty,
+    Json,
+}
+
+// 0: warnings only (or whatever RUST_LOG says), 1: debug, 2 and more: trace
+pub fn init(verbosity: u8, format: LogFormat) {
+    let mut builder = env_logger::Builder::from_default_env();
+
+    match verbosity {
+        0 if env::var_os(env_logger::DEFAULT_FILTER_ENV).is_none() => {
+            builder.filter_level(LevelFilter::Warn);
+        }
+        0 => {}
+        1 => {
+            builder.filter_level(LevelFilter::Debug);
+        }
+        _ => {
+            builder.filter_level(LevelFilter::Trace);
+        }
+    };
+
+    if format == LogFormat::Json {
+        builder.format(|buf, record| {
+            let event = serde_json::json!({
+                "timestamp": buf.timestamp().to_string(),
+                "level": record.level().as_str(),
+                "target": record.target(),
+                "message": record.args().to_string(),
+            });
+            writeln!(buf, "{event}")
+        });
+    }
+
+    // a second initialisation (e.g. in tests) keeps the first logger
+    let _ = builder.try_init();
+}
@@ -0,0 +1,39 @@
+// A splitmix64 generator: not good enough for anything but reproducible
+// tournaments and generated inputs, which only need the same numbers for the same
+// seed.
+#[derive(Debug, Clone)]
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // a number in 0..bound
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Random;
+
+    #[test]
+    fn splitmix64() {
+        let mut random = Random::new(0);
+
+        // the first outputs of the reference implementation for seed 0
+        assert_eq!(random.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(random.next_u64(), 0x6e789e6aa1b965f4);
+        assert!((0..100).all(|_| random.below(3) < 3));
+    }
+}
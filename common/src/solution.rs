@@ -0,0 +1,46 @@
+use crate::cli::Args;
+use crate::error::annotate;
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::fmt::Display;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Output>;
+    fn part2(input: &Self::Input) -> Result<Self::Output>;
+
+    // what parsing skipped over in the input, reported once by the runners
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        vec![]
+    }
+}
+
+// reads and parses the input given on the command line once and prints the result of both parts
+pub fn run<S: Solution>() -> Result<()> {
+    let args = Args::parse();
+    args.logging.init();
+
+    let (source, content) = args.load(S::DAY)?;
+    let input = S::parse(&content)
+        .map_err(|e| annotate(e, &content))
+        .context(format!("while parsing '{}'", source))?;
+    for warning in S::warnings(&input) {
+        eprintln!("{source}: {warning}");
+    }
+
+    println!(
+        "part1: {}",
+        S::part1(&input).map_err(|e| annotate(e, &content))?
+    );
+    println!(
+        "part2: {}",
+        S::part2(&input).map_err(|e| annotate(e, &content))?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use anyhow::{bail, Context, Result};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// A content addressed store for puzzle inputs:
+//
+//   <root>/manifest.json       day -> sha256 and size of its input
+//   <root>/objects/<sha256>    the input itself
+pub struct Store {
+    root: PathBuf,
+    manifest: BTreeMap<u8, Entry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub sha256: String,
+    pub size: usize,
+}
+
+impl Entry {
+    pub fn of(content: &[u8]) -> Self {
+        Entry {
+            sha256: format!("{:x}", Sha256::digest(content)),
+            size: content.len(),
+        }
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sha256 {} ({} bytes)", self.sha256, self.size)
+    }
+}
+
+impl Store {
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+        let manifest_path = root.join("manifest.json");
+
+        let manifest = if manifest_path.is_file() {
+            let manifest = fs::read_to_string(&manifest_path).context(format!(
+                "while reading manifest '{}'",
+                manifest_path.display()
+            ))?;
+            serde_json::from_str(&manifest).context(format!(
+                "while parsing manifest '{}'",
+                manifest_path.display()
+            ))?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Store { root, manifest })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn entry(&self, day: u8) -> Option<&Entry> {
+        self.manifest.get(&day)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (u8, &Entry)> {
+        self.manifest.iter().map(|(day, entry)| (*day, entry))
+    }
+
+    pub fn object_path(&self, day: u8) -> Option<PathBuf> {
+        self.entry(day)
+            .map(|entry| self.root.join("objects").join(&entry.sha256))
+    }
+
+    // stores `content` as the input of `day` and records it in the manifest
+    pub fn add(&mut self, day: u8, content: &str) -> Result<Entry> {
+        let entry = Entry::of(content.as_bytes());
+        let objects = self.root.join("objects");
+
+        fs::create_dir_all(&objects)
+            .context(format!("while creating directory '{}'", objects.display()))?;
+        let object_path = objects.join(&entry.sha256);
+        fs::write(&object_path, content)
+            .context(format!("while writing '{}'", object_path.display()))?;
+
+        self.manifest.insert(day, entry.clone());
+        self.save()?;
+
+        Ok(entry)
+    }
+
+    fn save(&self) -> Result<()> {
+        let manifest_path = self.root.join("manifest.json");
+        fs::write(
+            &manifest_path,
+            serde_json::to_string_pretty(&self.manifest)? + "\n",
+        )
+        .context(format!(
+            "while writing manifest '{}'",
+            manifest_path.display()
+        ))
+    }
+
+    // fails if `content`, read from `origin`, is not the input recorded for `day`;
+    // days without a manifest entry are not checked
+    pub fn verify(&self, day: u8, origin: &str, content: &str) -> Result<()> {
+        let Some(expected) = self.entry(day) else {
+            return Ok(());
+        };
+
+        let actual = Entry::of(content.as_bytes());
+        debug!("verifying input '{origin}' of day {day}: {actual}");
+        if actual != *expected {
+            bail!(
+                "input '{origin}' does not match the manifest of day {day} in '{}': expected {expected}, got {actual}",
+                self.root.display()
+            )
+        }
+
+        Ok(())
+    }
+
+    // like `verify`, but for a `reader` that fails at its end instead
+    pub fn verifying<R: Read>(&self, day: u8, origin: &str, reader: R) -> Verifying<R> {
+        let expected = self.entry(day).map(|expected| {
+            debug!("verifying input '{origin}' of day {day} while reading it");
+            let message = format!(
+                "input '{origin}' does not match the manifest of day {day} in '{}'",
+                self.root.display()
+            );
+            (expected.clone(), message)
+        });
+
+        Verifying {
+            reader,
+            hasher: Sha256::new(),
+            size: 0,
+            expected,
+        }
+    }
+}
+
+// Hashes an input while it is read and fails at its end if it doesn't match the
+// manifest entry, so that inputs can be verified without keeping them in memory.
+pub struct Verifying<R> {
+    reader: R,
+    hasher: Sha256,
+    size: usize,
+    // none once verified or if there is no manifest entry
+    expected: Option<(Entry, String)>,
+}
+
+impl<R: Read> Read for Verifying<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        self.size += read;
+
+        if read == 0 && !buf.is_empty() {
+            if let Some((expected, message)) = self.expected.take() {
+                let actual = Entry {
+                    sha256: format!("{:x}", self.hasher.clone().finalize()),
+                    size: self.size,
+                };
+                if actual != expected {
+                    let message = format!("{message}: expected {expected}, got {actual}");
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+                }
+            }
+        }
+
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entry, Store};
+    use std::fs;
+    use std::io::Read;
+    use std::path::PathBuf;
+
+    fn store_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-store-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn entry() {
+        assert_eq!(
+            Entry::of(b"abc"),
+            Entry {
+                sha256: "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".into(),
+                size: 3
+            }
+        );
+    }
+
+    #[test]
+    fn add_and_reopen() {
+        let dir = store_dir("add");
+        let mut store = Store::open(&dir).unwrap();
+        assert!(store.object_path(6).is_none());
+
+        let entry = store.add(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+
+        let store = Store::open(&dir).unwrap();
+        assert_eq!(store.entry(6), Some(&entry));
+        assert_eq!(
+            fs::read_to_string(store.object_path(6).unwrap()).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn verify() {
+        let dir = store_dir("verify");
+        let mut store = Store::open(&dir).unwrap();
+        store.add(8, "30373\n25512\n").unwrap();
+
+        assert!(store.verify(8, "input", "30373\n25512\n").is_ok());
+        assert!(store.verify(7, "input", "anything").is_ok());
+
+        let err = store.verify(8, "input", "30373\n2551").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("input 'input' does not match the manifest of day 8"));
+        assert!(err.to_string().contains("(12 bytes), got sha256"));
+        assert!(err.to_string().ends_with("(10 bytes)"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn verifying_reader() {
+        let dir = store_dir("verifying");
+        let mut store = Store::open(&dir).unwrap();
+        store.add(8, "30373\n25512\n").unwrap();
+        let read = |day, content: &str| {
+            let mut read = String::new();
+            store
+                .verifying(day, "input", content.as_bytes())
+                .read_to_string(&mut read)
+                .map(|_| read)
+        };
+
+        assert_eq!(read(8, "30373\n25512\n").unwrap(), "30373\n25512\n");
+        assert!(read(7, "anything").is_ok());
+        assert!(read(8, "30373\n2551")
+            .unwrap_err()
+            .to_string()
+            .starts_with("input 'input' does not match the manifest of day 8"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
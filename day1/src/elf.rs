@@ -0,0 +1,161 @@
+use crate::{ElfInventory, ParseMode};
+use anyhow::Result;
+use std::cmp::Ordering;
+use std::io::BufRead;
+use std::ops::{Range, RangeInclusive};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    // position of the elf in the input, starting at 1
+    pub index: usize,
+    // the lines of its items in the input
+    pub lines: RangeInclusive<usize>,
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().map(|&calories| calories as u64).sum()
+    }
+
+    // orders by total, on equal totals the elf coming first in the input is greater
+    pub(crate) fn cmp_load(&self, other: &Elf) -> Ordering {
+        self.total()
+            .cmp(&other.total())
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Elves(Vec<Elf>);
+
+impl From<Vec<Elf>> for Elves {
+    fn from(elves: Vec<Elf>) -> Self {
+        Elves(elves)
+    }
+}
+
+impl Elves {
+    pub fn read(reader: impl BufRead, mode: ParseMode) -> Result<Self> {
+        Ok(Elves(
+            ElfInventory::with_mode(reader, mode).collect::<Result<_>>()?,
+        ))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Elf> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // the elf carrying the most calories, the first one of them on a tie
+    pub fn heaviest(&self) -> Option<&Elf> {
+        self.iter().max_by(|a, b| a.cmp_load(b))
+    }
+
+    pub fn above(&self, threshold: u64) -> Vec<&Elf> {
+        self.iter().filter(|elf| elf.total() > threshold).collect()
+    }
+
+    // the mean of the two middle totals for an even number of elves
+    pub fn median(&self) -> Option<f64> {
+        let mut totals: Vec<u64> = self.iter().map(Elf::total).collect();
+        totals.sort_unstable();
+
+        let middle = totals.len() / 2;
+        match totals.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(totals[middle] as f64),
+            _ => Some((totals[middle - 1] + totals[middle]) as f64 / 2.0),
+        }
+    }
+
+    // number of elves per `width` wide bucket of totals, from the bucket of the lowest
+    // to the bucket of the highest total, including empty buckets in between
+    pub fn histogram(&self, width: u64) -> Vec<(Range<u64>, usize)> {
+        let width = width.max(1);
+        let buckets: Vec<u64> = self.iter().map(|elf| elf.total() / width).collect();
+        let (Some(&first), Some(&last)) = (buckets.iter().min(), buckets.iter().max()) else {
+            return vec![];
+        };
+
+        let mut counts = vec![0; (last - first + 1) as usize];
+        for bucket in buckets {
+            counts[(bucket - first) as usize] += 1;
+        }
+
+        counts
+            .into_iter()
+            .zip(first..)
+            .map(|(count, bucket)| (bucket * width..(bucket + 1) * width, count))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Elf, Elves};
+    use crate::ParseMode;
+
+    fn elves(input: &str) -> Elves {
+        Elves::read(input.as_bytes(), ParseMode::Strict).unwrap()
+    }
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn heaviest() {
+        assert_eq!(
+            elves(INPUT).heaviest(),
+            Some(&Elf {
+                index: 4,
+                lines: 10..=12,
+                items: vec![7000, 8000, 9000]
+            })
+        );
+        assert_eq!(elves("1\n\n2\n\n1\n1\n").heaviest().unwrap().index, 2);
+        assert_eq!(elves("").heaviest(), None);
+    }
+
+    #[test]
+    fn above() {
+        let indices = |threshold| {
+            elves(INPUT)
+                .above(threshold)
+                .iter()
+                .map(|elf| elf.index)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(indices(10000), vec![3, 4]);
+        assert_eq!(indices(24000), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(elves(INPUT).median(), Some(10000.0));
+        assert_eq!(elves("1\n\n2\n").median(), Some(1.5));
+        assert_eq!(elves("").median(), None);
+    }
+
+    #[test]
+    fn histogram() {
+        assert_eq!(
+            elves(INPUT).histogram(5000),
+            vec![
+                (0..5000, 1),
+                (5000..10000, 1),
+                (10000..15000, 2),
+                (15000..20000, 0),
+                (20000..25000, 1)
+            ]
+        );
+        assert_eq!(elves("").histogram(10), vec![]);
+    }
+}
@@ -0,0 +1,21 @@
+use common::error::{Span, Spanned};
+use std::num::ParseIntError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("expected the calories of an item or a blank line, got '{}'", .span.text)]
+    InvalidCalories {
+        span: Span,
+        #[source]
+        source: ParseIntError,
+    },
+}
+
+impl Spanned for ParseError {
+    fn span(&self) -> &Span {
+        match self {
+            ParseError::InvalidCalories { span, .. } => span,
+        }
+    }
+}
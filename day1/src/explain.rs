@@ -0,0 +1,105 @@
+use crate::Elf;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+// which elves an answer is made of
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub part: u8,
+    pub answer: u64,
+    pub elves: Vec<Subtotal>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Subtotal {
+    pub elf: usize,
+    pub first_line: usize,
+    pub last_line: usize,
+    pub items: Vec<u32>,
+    pub total: u64,
+}
+
+impl From<&Elf> for Subtotal {
+    fn from(elf: &Elf) -> Self {
+        Subtotal {
+            elf: elf.index,
+            first_line: *elf.lines.start(),
+            last_line: *elf.lines.end(),
+            items: elf.items.clone(),
+            total: elf.total(),
+        }
+    }
+}
+
+// explains both parts given the heaviest elves, heaviest first
+pub fn explain(top: &[Elf]) -> Vec<Explanation> {
+    [(1, 1), (2, 3)]
+        .into_iter()
+        .map(|(part, elves)| {
+            let elves: Vec<Subtotal> = top.iter().take(elves).map(Subtotal::from).collect();
+            Explanation {
+                part,
+                answer: elves.iter().map(|elf| elf.total).sum(),
+                elves,
+            }
+        })
+        .collect()
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "part{}: {}", self.part, self.answer)?;
+        for elf in &self.elves {
+            let items = elf
+                .items
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(" + ");
+            writeln!(
+                f,
+                "  elf {} (lines {}-{}): {items} = {}",
+                elf.elf, elf.first_line, elf.last_line, elf.total
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::explain;
+    use crate::ElfInventory;
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn parts() {
+        let top = ElfInventory::new(INPUT.as_bytes()).top(3).unwrap();
+        let explanations = explain(&top);
+
+        assert_eq!(
+            explanations
+                .iter()
+                .map(|explanation| explanation.to_string())
+                .collect::<String>(),
+            "part1: 24000
+  elf 4 (lines 10-12): 7000 + 8000 + 9000 = 24000
+part2: 45000
+  elf 4 (lines 10-12): 7000 + 8000 + 9000 = 24000
+  elf 3 (lines 7-8): 5000 + 6000 = 11000
+  elf 5 (lines 14-14): 10000 = 10000
+"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let top = ElfInventory::new("3\n4\n".as_bytes()).top(3).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&explain(&top)[0]).unwrap(),
+            r#"{"part":1,"answer":7,"elves":[{"elf":1,"first_line":1,"last_line":2,"items":[3,4],"total":7}]}"#
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::elf::Elf;
+use crate::ParseError;
+use anyhow::{Context, Result};
+use common::error::{Diagnostic, Line};
+use log::debug;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::io::{BufRead, Lines};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    // groups are separated by blank lines, every other line has to be a number
+    #[default]
+    Strict,
+    // every line that isn't a number of calories separates groups, negative
+    // numbers included
+    Lenient,
+}
+
+// Streams the elves and the calories of their items from `reader`, one group of
+// lines at a time.
+pub struct ElfInventory<R> {
+    lines: Lines<R>,
+    mode: ParseMode,
+    line_number: usize,
+    elves: usize,
+}
+
+// orders the heap of `ElfInventory::top` by the load of the elves
+struct Load(Elf);
+
+impl PartialEq for Load {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Load {}
+
+impl PartialOrd for Load {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Load {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_load(&other.0)
+    }
+}
+
+impl<R: BufRead> ElfInventory<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_mode(reader, ParseMode::Strict)
+    }
+
+    pub fn with_mode(reader: R, mode: ParseMode) -> Self {
+        ElfInventory {
+            lines: reader.lines(),
+            mode,
+            line_number: 0,
+            elves: 0,
+        }
+    }
+
+    // the `n` elves carrying the most calories, heaviest first; only `n` elves are
+    // kept in memory
+    pub fn top(self, n: usize) -> Result<Vec<Elf>> {
+        let mut top = BinaryHeap::with_capacity(n + 1);
+
+        for elf in self {
+            top.push(Reverse(Load(elf?)));
+            if top.len() > n {
+                top.pop();
+            }
+        }
+
+        // sorting the reversed loads ascending yields the heaviest elf first
+        Ok(top
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(Load(elf))| elf)
+            .collect())
+    }
+}
+
+impl<R: BufRead> Iterator for ElfInventory<R> {
+    type Item = Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = vec![];
+        let (mut first_line, mut last_line) = (0, 0);
+
+        for line in self.lines.by_ref() {
+            self.line_number += 1;
+            let line = match line.context(format!("while reading line {}", self.line_number)) {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+
+            match (line.parse::<u32>(), self.mode) {
+                (Ok(calories), _) => {
+                    if items.is_empty() {
+                        first_line = self.line_number;
+                    }
+                    last_line = self.line_number;
+                    items.push(calories);
+                }
+                (Err(source), ParseMode::Strict) if !line.trim().is_empty() => {
+                    let line = Line::new(self.line_number, &line);
+                    let error = ParseError::InvalidCalories {
+                        span: line.span(line.text.trim()),
+                        source,
+                    };
+                    return Some(Err(Diagnostic::from(error).into()));
+                }
+                // a separator
+                _ if items.is_empty() => {}
+                _ => break,
+            }
+        }
+
+        if items.is_empty() {
+            debug!("read the items of {} elves", self.elves);
+            return None;
+        }
+
+        self.elves += 1;
+        Some(Ok(Elf {
+            index: self.elves,
+            lines: first_line..=last_line,
+            items,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElfInventory, ParseMode};
+    use common::error::Diagnostic;
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    fn totals(input: &str, mode: ParseMode) -> Vec<u64> {
+        ElfInventory::with_mode(input.as_bytes(), mode)
+            .map(|elf| elf.unwrap().total())
+            .collect()
+    }
+
+    #[test]
+    fn totals_per_elf() {
+        for mode in [ParseMode::Strict, ParseMode::Lenient] {
+            assert_eq!(totals(INPUT, mode), vec![6000, 4000, 11000, 24000, 10000]);
+        }
+    }
+
+    #[test]
+    fn lines() {
+        let lines: Vec<_> = ElfInventory::new("\n1\n2\n\n\n3\n".as_bytes())
+            .map(|elf| elf.unwrap().lines)
+            .collect();
+
+        assert_eq!(lines, vec![2..=3, 6..=6]);
+    }
+
+    #[test]
+    fn consecutive_separators() {
+        assert_eq!(
+            totals("\n\n1\n\n \n\n2\n3\n\n", ParseMode::Strict),
+            vec![1, 5]
+        );
+    }
+
+    #[test]
+    fn lenient_separators() {
+        assert_eq!(
+            totals("1\n12a4\n2\n-3\n4\n", ParseMode::Lenient),
+            vec![1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn strict_errors() {
+        let error = |input: &str| {
+            let error = ElfInventory::new(input.as_bytes())
+                .find_map(Result::err)
+                .unwrap();
+            let diagnostic = error.downcast::<Diagnostic>().unwrap();
+            let span = diagnostic.span().clone();
+            (span.line, span.column, span.text, diagnostic.to_string())
+        };
+
+        assert_eq!(
+            error("1000\n\n12a4\n"),
+            (
+                3,
+                1,
+                "12a4".to_string(),
+                "expected the calories of an item or a blank line, got '12a4'".to_string()
+            )
+        );
+        assert_eq!(error("1\n  -3\n").1, 3);
+        assert_eq!(error("99999999999\n").2, "99999999999");
+    }
+
+    #[test]
+    fn top() {
+        let top = |n| {
+            ElfInventory::new(INPUT.as_bytes())
+                .top(n)
+                .unwrap()
+                .iter()
+                .map(|elf| (elf.index, elf.total()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(top(0), vec![]);
+        assert_eq!(top(1), vec![(4, 24000)]);
+        assert_eq!(top(3), vec![(4, 24000), (3, 11000), (5, 10000)]);
+        assert_eq!(top(10).len(), 5);
+    }
+
+    #[test]
+    fn top_prefers_earlier_elves_on_ties() {
+        let top = ElfInventory::new("5\n\n3\n2\n\n1\n".as_bytes())
+            .top(1)
+            .unwrap();
+
+        assert_eq!(top[0].index, 1);
+    }
+}
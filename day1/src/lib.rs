@@ -0,0 +1,67 @@
+use anyhow::Result;
+use common::Solution;
+pub use elf::{Elf, Elves};
+pub use error::ParseError;
+pub use explain::{explain, Explanation, Subtotal};
+pub use inventory::{ElfInventory, ParseMode};
+
+mod elf;
+mod error;
+mod explain;
+mod inventory;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    // the three elves carrying the most calories, heaviest first
+    type Input = Vec<Elf>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        ElfInventory::new(input.as_bytes()).top(3)
+    }
+
+    fn part1(top: &Self::Input) -> Result<Self::Output> {
+        Ok(top.first().map(Elf::total).unwrap_or_default())
+    }
+
+    fn part2(top: &Self::Input) -> Result<Self::Output> {
+        Ok(top.iter().map(Elf::total).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day1;
+    use common::Solution;
+
+    const INPUT: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+"#;
+
+    #[test]
+    fn part1() {
+        let calories = Day1::parse(INPUT).unwrap();
+        assert_eq!(Day1::part1(&calories).unwrap(), 24000);
+    }
+
+    #[test]
+    fn part2() {
+        let calories = Day1::parse(INPUT).unwrap();
+        assert_eq!(Day1::part2(&calories).unwrap(), 45000);
+    }
+}
@@ -1,33 +1,129 @@
 use anyhow::{Context, Result};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use clap::{Parser, ValueEnum};
+use common::cli::Args;
+use common::error::locate;
+use common::Solution;
+use day1::{explain, Day1, Elf, ElfInventory, Elves, ParseMode};
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+
+    /// Treat every line that isn't a number as a group separator instead of only blank lines
+    #[arg(long)]
+    lenient: bool,
+
+    /// Print the N heaviest elves instead of the answers
+    #[arg(long, value_name = "N")]
+    top: Option<usize>,
+
+    /// Print the elves, lines and items both answers are made of
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        conflicts_with_all = ["top", "report"]
+    )]
+    explain: Option<ExplainFormat>,
+
+    /// Print a report about the load of all elves instead of the answers
+    #[arg(long, conflicts_with = "top")]
+    report: bool,
+
+    /// List the elves carrying more than this in the report [default: the median]
+    #[arg(long, requires = "report")]
+    threshold: Option<u64>,
+
+    /// Width of the histogram buckets of the report [default: a tenth of the heaviest load]
+    #[arg(long, requires = "report")]
+    bucket_width: Option<u64>,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum ExplainFormat {
+    Text,
+    Json,
+}
 
 fn main() -> Result<()> {
-    let file = "input";
-    let reader =
-        BufReader::new(File::open(file).context(format!("while opening file '{}'", &file))?);
-    let mut calories = vec![0];
-
-    for (cnt, line) in reader.lines().enumerate() {
-        match line
-            .context(format!("while reading line {} from file '{}'", cnt, file))?
-            .parse::<i32>()
-        {
-            Ok(number) => calories[0] += number,
-            Err(_) => calories.insert(0, 0),
+    let cli = Cli::parse();
+    cli.args.logging.init();
+
+    let (source, reader) = cli.args.open(Day1::DAY)?;
+    let context = format!("while reading '{}'", source);
+    let mode = match cli.lenient {
+        true => ParseMode::Lenient,
+        false => ParseMode::Strict,
+    };
+
+    if cli.report {
+        let elves = Elves::read(reader, mode).map_err(locate).context(context)?;
+        report(&elves, cli.threshold, cli.bucket_width);
+        return Ok(());
+    }
+
+    let top = ElfInventory::with_mode(reader, mode)
+        .top(cli.top.unwrap_or(3))
+        .map_err(locate)
+        .context(context)?;
+
+    match cli.top {
+        Some(_) => {
+            for (rank, elf) in top.iter().enumerate() {
+                println!("{}. elf {}: {}", rank + 1, elf.index, elf.total());
+            }
+            println!("total: {}", top.iter().map(Elf::total).sum::<u64>());
         }
+        None => match cli.explain {
+            Some(ExplainFormat::Text) => {
+                for explanation in explain(&top) {
+                    print!("{explanation}");
+                }
+            }
+            Some(ExplainFormat::Json) => {
+                println!("{}", serde_json::to_string_pretty(&explain(&top))?)
+            }
+            None => {
+                println!("part1: {}", Day1::part1(&top)?);
+                println!("part2: {}", Day1::part2(&top)?);
+            }
+        },
     }
 
-    calories.sort_unstable();
+    Ok(())
+}
+
+fn report(elves: &Elves, threshold: Option<u64>, bucket_width: Option<u64>) {
+    let (Some(heaviest), Some(median)) = (elves.heaviest(), elves.median()) else {
+        println!("no elves");
+        return;
+    };
+
+    println!("elves:    {}", elves.len());
     println!(
-        "part 1: {}\npart 2: {}",
-        calories.last().unwrap(),
-        calories
-            .get(calories.len() - 3..)
-            .unwrap()
-            .iter()
-            .sum::<i32>()
+        "heaviest: elf {} carrying {} calories in {} items",
+        heaviest.index,
+        heaviest.total(),
+        heaviest.items.len()
     );
+    println!("median:   {median}");
 
-    Ok(())
+    let threshold = threshold.unwrap_or(median as u64);
+    let above = elves.above(threshold);
+    println!("{} elves carry more than {threshold}:", above.len());
+    for elf in above {
+        println!("  elf {}: {}", elf.index, elf.total());
+    }
+
+    let histogram = elves.histogram(bucket_width.unwrap_or(heaviest.total().div_ceil(10)));
+    let most = histogram.iter().map(|(_, count)| *count).max().unwrap_or(1);
+    println!("histogram:");
+    for (bucket, count) in histogram {
+        let bar = "#".repeat((count * 50).div_ceil(most));
+        println!("  {:>8}..{:<8} {count:>5} {bar}", bucket.start, bucket.end);
+    }
 }
@@ -0,0 +1,28 @@
+use common::error::{Span, Spanned};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("unknown shape '{0}', expected one of A, B, C")]
+    UnknownShape(String),
+    #[error("unknown strategy '{0}', expected one of X, Y, Z")]
+    UnknownColumn(String),
+    #[error("unknown letter '{letter}', expected one of {expected}")]
+    UnknownLetter { letter: String, expected: String },
+    #[error("expected a round like 'A Y', got '{0}'")]
+    InvalidRound(String),
+}
+
+// a line of the strategy guide that isn't a round
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{reason}")]
+pub struct RejectedLine {
+    pub span: Span,
+    pub reason: ParseError,
+}
+
+impl Spanned for RejectedLine {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{ParseError, Rules};
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+// the scores and which shape beats which come from the rules of Rock Paper Scissors,
+// whose shapes are in the order of `Shape::ALL`
+static RPS: LazyLock<Rules> = LazyLock::new(Rules::rps);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+// a round as I play it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub me: Shape,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    pub fn score(self) -> u32 {
+        RPS.shapes[self as usize].score
+    }
+
+    pub fn beats(self, other: Shape) -> bool {
+        RPS.beats(self as usize, other as usize)
+    }
+
+    // the outcome of playing `self` against `opponent`
+    pub fn against(self, opponent: Shape) -> Outcome {
+        if self.beats(opponent) {
+            Outcome::Win
+        } else if opponent.beats(self) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // the shape that has to be played against `opponent` to get `outcome`
+    pub fn for_outcome(opponent: Shape, outcome: Outcome) -> Shape {
+        Shape::ALL
+            .into_iter()
+            .find(|me| me.against(opponent) == outcome)
+            .expect("every outcome is reachable against every shape")
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissors),
+            _ => Err(ParseError::UnknownShape(s.to_string())),
+        }
+    }
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        RPS.outcomes.score(self)
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Outcome::Loss),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(ParseError::UnknownColumn(s.to_string())),
+        }
+    }
+}
+
+impl Round {
+    pub fn outcome(&self) -> Outcome {
+        self.me.against(self.opponent)
+    }
+
+    pub fn score(&self) -> u32 {
+        self.me.score() + self.outcome().score()
+    }
+}
+
+// rounds are like "A Y": the shape of the opponent and the outcome I have to get
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent, outcome) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::InvalidRound(s.to_string()))?;
+        let opponent = opponent.parse()?;
+
+        Ok(Round {
+            opponent,
+            me: Shape::for_outcome(opponent, outcome.parse()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Outcome, Round, Shape};
+    use crate::ParseError;
+
+    #[test]
+    fn beats() {
+        for shape in Shape::ALL {
+            assert!(!shape.beats(shape));
+            // every shape beats exactly one other shape
+            assert_eq!(
+                Shape::ALL
+                    .iter()
+                    .filter(|other| shape.beats(**other))
+                    .count(),
+                1
+            );
+        }
+        assert!(Shape::Paper.beats(Shape::Rock));
+        assert_eq!(Shape::Rock.against(Shape::Paper), Outcome::Loss);
+    }
+
+    #[test]
+    fn for_outcome() {
+        for opponent in Shape::ALL {
+            for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+                assert_eq!(
+                    Shape::for_outcome(opponent, outcome).against(opponent),
+                    outcome
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round() {
+        let round = |opponent, me| Round { opponent, me };
+
+        assert_eq!(round(Shape::Rock, Shape::Paper).score(), 8);
+        assert_eq!(round(Shape::Paper, Shape::Rock).score(), 1);
+        assert_eq!(round(Shape::Scissors, Shape::Scissors).score(), 6);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("Z".parse(), Ok(Outcome::Win));
+        assert_eq!(
+            "A Y".parse(),
+            Ok(Round {
+                opponent: Shape::Rock,
+                me: Shape::Rock
+            })
+        );
+        assert_eq!("C X".parse::<Round>().map(|r| r.score()), Ok(2));
+        assert_eq!(
+            "A W".parse::<Round>(),
+            Err(ParseError::UnknownColumn("W".to_string()))
+        );
+        assert_eq!(
+            "AY".parse::<Round>(),
+            Err(ParseError::InvalidRound("AY".to_string()))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use common::Solution;
+pub use error::{ParseError, RejectedLine};
+pub use game::{Outcome, Round, Shape};
+use log::debug;
+pub use rules::{OutcomeScores, Play, Rules, ShapeRule};
+pub use strategy::{
+    parse_guide, score, Column, ColumnIsOutcome, ColumnIsShape, Guide, Instruction,
+    StrategyInterpretation,
+};
+pub use tournament::{simulate, Model, Opponent, Report};
+pub use trace::{csv, table, trace, Step};
+
+mod error;
+mod game;
+mod rules;
+mod strategy;
+mod tournament;
+mod trace;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Guide;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (instructions, rejected) = parse_guide(input);
+        debug!(
+            "parsed {} instructions, skipped {} lines",
+            instructions.len(),
+            rejected.len()
+        );
+
+        Ok(Guide {
+            instructions,
+            rejected,
+        })
+    }
+
+    fn part1(guide: &Self::Input) -> Result<Self::Output> {
+        Ok(score(&guide.instructions, &ColumnIsShape))
+    }
+
+    fn part2(guide: &Self::Input) -> Result<Self::Output> {
+        Ok(score(&guide.instructions, &ColumnIsOutcome))
+    }
+
+    fn warnings(guide: &Self::Input) -> Vec<String> {
+        let mut warnings: Vec<String> = guide
+            .rejected
+            .iter()
+            .map(|line| format!("skipping line {}: {line}", line.span.line))
+            .collect();
+        if !guide.rejected.is_empty() {
+            warnings.push(format!(
+                "skipped {} of {} lines",
+                guide.rejected.len(),
+                guide.rejected.len() + guide.instructions.len()
+            ));
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day2;
+    use common::Solution;
+
+    #[test]
+    fn part1() {
+        let instructions = Day2::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(Day2::part1(&instructions).unwrap(), 15);
+    }
+
+    #[test]
+    fn part2() {
+        let instructions = Day2::parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(Day2::part2(&instructions).unwrap(), 12);
+    }
+
+    #[test]
+    fn warnings() {
+        let guide = Day2::parse("A Y\nAY\nC Z\n").unwrap();
+
+        assert_eq!(
+            Day2::warnings(&guide),
+            vec![
+                "skipping line 2: expected a round like 'A Y', got 'AY'",
+                "skipped 1 of 3 lines"
+            ]
+        );
+        assert!(Day2::warnings(&Day2::parse("A Y\n").unwrap()).is_empty());
+    }
+}
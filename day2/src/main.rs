@@ -1,33 +1,148 @@
 use anyhow::{Context, Result};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use clap::{Parser, ValueEnum};
+use common::cli::Args;
+use common::error::{annotate, Diagnostic};
+use common::input::Source;
+use common::Solution;
+use day2::{
+    csv, parse_guide, simulate, table, trace, ColumnIsOutcome, ColumnIsShape, Day2, Guide, Model,
+    RejectedLine, Rules, StrategyInterpretation,
+};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+
+    /// Fail on the first line that isn't a round instead of skipping it
+    #[arg(long)]
+    strict: bool,
+
+    /// Score the guide, read as 'opponent's shape, my shape', by the game in this rules file instead
+    #[arg(long, value_name = "FILE")]
+    rules: Option<PathBuf>,
+
+    /// Play the guide against opponent models and report how it does
+    #[arg(long, conflicts_with = "rules")]
+    simulate: bool,
+
+    /// Opponent model: fixed:<rock|paper|scissors>, random:<seed>, frequency or pattern [default: all]
+    #[arg(long = "opponent", value_name = "MODEL", requires = "simulate")]
+    opponents: Vec<Model>,
+
+    /// Print the shapes, outcome and scores of every round with the running total
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "table",
+        conflicts_with_all = ["rules", "simulate"]
+    )]
+    trace: Option<TraceFormat>,
+
+    /// How the second column of the guide is read in the simulation and the trace
+    #[arg(long, value_enum, default_value_t = Interpretation::Outcome, conflicts_with = "rules")]
+    interpretation: Interpretation,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum TraceFormat {
+    Table,
+    Csv,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum Interpretation {
+    /// The shape I play (part 1)
+    Shape,
+    /// The outcome I have to get against the opponent's shape in the first column (part 2)
+    Outcome,
+}
+
+// reports the lines of the guide that aren't rounds, or fails on the first of them
+// when `strict`
+fn skip(
+    source: &Source,
+    input: &str,
+    rejected: &[RejectedLine],
+    parsed: usize,
+    strict: bool,
+) -> Result<()> {
+    if let Some(first) = rejected.first().filter(|_| strict) {
+        let error = anyhow::Error::from(Diagnostic::from(first.clone()));
+        return Err(annotate(error, input)).context(format!("while parsing '{}'", source));
+    }
+    for line in rejected {
+        eprintln!("{source}: skipping line {}: {line}", line.span.line);
+    }
+    if !rejected.is_empty() {
+        eprintln!(
+            "{source}: skipped {} of {} lines",
+            rejected.len(),
+            rejected.len() + parsed
+        );
+    }
+
+    Ok(())
+}
 
 fn main() -> Result<()> {
-    let file = "input";
-    let reader =
-        BufReader::new(File::open(file).context(format!("while opening file '{}'", &file))?);
-
-    let result = reader
-        .lines()
-        .fold(0, |acc, line| match line.unwrap().as_ref() {
-            // Rock: 1
-            // Paper: 2
-            // Scissors: 3
-            "A X" => acc + 0 + 3, // lose: Rock vs. Scissors
-            "B X" => acc + 0 + 1, // lose: Paper vs. Rock
-            "C X" => acc + 0 + 2, // lose: Scissors vs. Paper
-
-            "A Y" => acc + 3 + 1, // draw: Rock     vs. Rock
-            "B Y" => acc + 3 + 2, // draw: Paper    vs. Paper
-            "C Y" => acc + 3 + 3, // draw: Scissors vs. Scissors
-
-            "A Z" => acc + 6 + 2, // win: Rock     vs. Paper
-            "B Z" => acc + 6 + 3, // win: Paper    vs. Scissors
-            "C Z" => acc + 6 + 1, // win: Scissors vs. Rock
-            _ => acc,
-        });
-
-    println!("result: {}", result);
+    let cli = Cli::parse();
+    cli.args.logging.init();
+
+    let (source, input) = cli.args.load(Day2::DAY)?;
+
+    if let Some(rules) = cli.rules {
+        let rules = Rules::load(rules)?;
+        let (plays, rejected) = rules.parse_guide(&input);
+        skip(&source, &input, &rejected, plays.len(), cli.strict)?;
+        println!("score: {}", rules.score_guide(&plays));
+        return Ok(());
+    }
+
+    let (instructions, rejected) = parse_guide(&input);
+    skip(&source, &input, &rejected, instructions.len(), cli.strict)?;
+
+    let interpretation: &dyn StrategyInterpretation = match cli.interpretation {
+        Interpretation::Shape => &ColumnIsShape,
+        Interpretation::Outcome => &ColumnIsOutcome,
+    };
+
+    if let Some(format) = cli.trace {
+        let steps = trace(&instructions, interpretation);
+        match format {
+            TraceFormat::Table => print!("{}", table(&steps)),
+            TraceFormat::Csv => print!("{}", csv(&steps)),
+        }
+        return Ok(());
+    }
+
+    if cli.simulate {
+        let my_shapes: Vec<_> = instructions
+            .iter()
+            .map(|instruction| interpretation.round(instruction).me)
+            .collect();
+
+        let models = match cli.opponents.is_empty() {
+            true => Model::defaults(),
+            false => cli.opponents,
+        };
+        for model in models {
+            let report = simulate(&my_shapes, model.opponent().as_mut());
+            println!("{:<15} {report}", model.to_string());
+        }
+        return Ok(());
+    }
+
+    let guide = Guide {
+        instructions,
+        rejected,
+    };
+    println!("part1: {}", Day2::part1(&guide)?);
+    println!("part2: {}", Day2::part2(&guide)?);
 
     Ok(())
 }
@@ -0,0 +1,290 @@
+use crate::strategy::{columns, parse_lines, reject};
+use crate::{Outcome, ParseError, RejectedLine};
+use anyhow::{bail, Context, Result};
+use common::error::Line;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+const RPS: &str = include_str!("../rules/rps.toml");
+const RPSLS: &str = include_str!("../rules/rpsls.toml");
+
+// A cyclic game with an odd number of shapes, listed in dominance order: every
+// shape beats the (N - 1) / 2 shapes before it, wrapping around at the start.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Rules {
+    pub shapes: Vec<ShapeRule>,
+    pub outcomes: OutcomeScores,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShapeRule {
+    pub name: String,
+    // the letters standing for this shape in the opponent's and in my column of a
+    // strategy guide
+    pub opponent: String,
+    pub me: String,
+    pub score: u32,
+}
+
+// a round of a guide like "A Y" by the shapes' indices in the rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub opponent: usize,
+    pub me: usize,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OutcomeScores {
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl OutcomeScores {
+    pub fn score(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+impl Rules {
+    pub fn rps() -> Self {
+        Self::parse(RPS).expect("rps.toml is valid")
+    }
+
+    pub fn rpsls() -> Self {
+        Self::parse(RPSLS).expect("rpsls.toml is valid")
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let rules =
+            fs::read_to_string(path).context(format!("while opening file '{}'", path.display()))?;
+        Self::parse(&rules).context(format!("while parsing rules '{}'", path.display()))
+    }
+
+    pub fn parse(rules: &str) -> Result<Self> {
+        let rules: Rules = toml::from_str(rules)?;
+
+        let n = rules.shapes.len();
+        if n < 3 || n.is_multiple_of(2) {
+            bail!("a cyclic game needs an odd number of at least 3 shapes, got {n}")
+        }
+
+        let mut names = HashSet::new();
+        let mut opponent = HashSet::new();
+        let mut me = HashSet::new();
+        for shape in &rules.shapes {
+            if !names.insert(&shape.name) {
+                bail!("shape '{}' is defined twice", shape.name)
+            }
+            if !opponent.insert(&shape.opponent) {
+                bail!(
+                    "opponent's letter '{}' stands for more than one shape",
+                    shape.opponent
+                )
+            }
+            if !me.insert(&shape.me) {
+                bail!("my letter '{}' stands for more than one shape", shape.me)
+            }
+        }
+
+        Ok(rules)
+    }
+
+    pub fn beats(&self, shape: usize, other: usize) -> bool {
+        let n = self.shapes.len();
+        let distance = (shape + n - other) % n;
+
+        (1..=(n - 1) / 2).contains(&distance)
+    }
+
+    pub fn outcome(&self, me: usize, opponent: usize) -> Outcome {
+        if self.beats(me, opponent) {
+            Outcome::Win
+        } else if self.beats(opponent, me) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn score(&self, me: usize, opponent: usize) -> u32 {
+        self.shapes[me].score + self.outcomes.score(self.outcome(me, opponent))
+    }
+
+    // a line like "A Y": the opponent's letter and mine
+    pub fn parse_play(&self, line: &Line) -> Result<Play, RejectedLine> {
+        let (opponent, me) = columns(line)?;
+        let shape = |letter: &str, column: fn(&ShapeRule) -> &String| {
+            self.shapes
+                .iter()
+                .position(|shape| column(shape) == letter)
+                .ok_or_else(|| {
+                    let expected: Vec<&str> =
+                        self.shapes.iter().map(|s| column(s).as_str()).collect();
+                    let reason = ParseError::UnknownLetter {
+                        letter: letter.to_string(),
+                        expected: expected.join(", "),
+                    };
+                    reject(line, letter, reason)
+                })
+        };
+
+        Ok(Play {
+            opponent: shape(opponent, |shape| &shape.opponent)?,
+            me: shape(me, |shape| &shape.me)?,
+        })
+    }
+
+    // the rounds of `guide` and the lines that aren't rounds; blank lines are ignored
+    pub fn parse_guide(&self, guide: &str) -> (Vec<Play>, Vec<RejectedLine>) {
+        parse_lines(guide, |line| self.parse_play(line))
+    }
+
+    pub fn score_guide(&self, plays: &[Play]) -> u32 {
+        plays
+            .iter()
+            .map(|play| self.score(play.me, play.opponent))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Play, Rules};
+    use crate::{Outcome, ParseError, Shape};
+    use common::error::{Line, Spanned};
+
+    #[test]
+    fn rps_matches_shapes() {
+        let rules = Rules::rps();
+
+        // `Shape` takes its scores and what beats what from these rules by position
+        for (shape, rule) in Shape::ALL.iter().zip(&rules.shapes) {
+            assert_eq!(format!("{shape:?}"), rule.name);
+        }
+        assert_eq!(rules.shapes.len(), Shape::ALL.len());
+
+        let (plays, rejected) = rules.parse_guide("A Y\nB X\nC Z\n");
+        assert!(rejected.is_empty());
+        assert_eq!(rules.score_guide(&plays), 15);
+    }
+
+    #[test]
+    fn rpsls() {
+        let rules = Rules::rpsls();
+        let shape = |name: &str| rules.shapes.iter().position(|s| s.name == name).unwrap();
+        let beats = |a, b| rules.beats(shape(a), shape(b));
+
+        // "Scissors cuts Paper, Paper covers Rock, Rock crushes Lizard, Lizard poisons
+        // Spock, Spock smashes Scissors, Scissors decapitates Lizard, Lizard eats Paper,
+        // Paper disproves Spock, Spock vaporizes Rock, Rock crushes Scissors"
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert!(beats(winner, loser), "{winner} beats {loser}");
+            assert!(!beats(loser, winner), "{loser} doesn't beat {winner}");
+        }
+        assert_eq!(
+            rules.outcome(shape("Lizard"), shape("Lizard")),
+            Outcome::Draw
+        );
+
+        // Rock vs. Spock: loss; Lizard vs. Lizard: draw; Scissors vs. Paper: win
+        let (plays, _) = rules.parse_guide("B V\nD Y\nC Z\n");
+        assert_eq!(rules.score_guide(&plays), 1 + 7 + 11);
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let shape = |name: &str, letter: &str| {
+            format!("[[shapes]]\nname = \"{name}\"\nopponent = \"{letter}\"\nme = \"{letter}\"\nscore = 1\n")
+        };
+        let rules = |shapes: &[String]| {
+            let rules = format!(
+                "[outcomes]\nloss = 0\ndraw = 3\nwin = 6\n{}",
+                shapes.concat()
+            );
+            Rules::parse(&rules).map_err(|e| e.to_string())
+        };
+
+        assert_eq!(
+            rules(&[shape("Rock", "A"), shape("Paper", "B")]).unwrap_err(),
+            "a cyclic game needs an odd number of at least 3 shapes, got 2"
+        );
+        assert_eq!(
+            rules(&[shape("Rock", "A"), shape("Rock", "B"), shape("Paper", "C")]).unwrap_err(),
+            "shape 'Rock' is defined twice"
+        );
+        assert_eq!(
+            rules(&[
+                shape("Rock", "A"),
+                shape("Paper", "B"),
+                shape("Scissors", "A")
+            ])
+            .unwrap_err(),
+            "opponent's letter 'A' stands for more than one shape"
+        );
+    }
+
+    #[test]
+    fn guide_errors() {
+        let rules = Rules::rps();
+
+        assert_eq!(
+            rules.parse_play(&Line::new(1, "C X")),
+            Ok(Play { opponent: 2, me: 0 })
+        );
+
+        // the letters of a column don't stand for shapes in the other one
+        let (plays, rejected) = rules.parse_guide("A Y\n\nX A\nA W\nAY\n");
+        assert_eq!(plays.len(), 1);
+        assert_eq!(
+            rejected
+                .iter()
+                .map(|rejected| {
+                    let span = rejected.span();
+                    (span.line, span.column, rejected.reason.clone())
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    3,
+                    1,
+                    ParseError::UnknownLetter {
+                        letter: "X".to_string(),
+                        expected: "A, B, C".to_string()
+                    }
+                ),
+                (
+                    4,
+                    3,
+                    ParseError::UnknownLetter {
+                        letter: "W".to_string(),
+                        expected: "X, Y, Z".to_string()
+                    }
+                ),
+                (5, 1, ParseError::InvalidRound("AY".to_string())),
+            ]
+        );
+        assert_eq!(
+            rejected[1].to_string(),
+            "unknown letter 'W', expected one of X, Y, Z"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{Outcome, ParseError, RejectedLine, Round, Shape};
+use common::error::Line;
+use std::str::FromStr;
+
+// the second column of the strategy guide, its meaning is up to the interpretation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+// a line of the strategy guide like "A Y"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opponent: Shape,
+    pub column: Column,
+}
+
+// a parsed strategy guide and the lines of it that aren't rounds
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Guide {
+    pub instructions: Vec<Instruction>,
+    pub rejected: Vec<RejectedLine>,
+}
+
+pub trait StrategyInterpretation {
+    fn round(&self, instruction: &Instruction) -> Round;
+}
+
+// part 1: the second column is the shape I play
+pub struct ColumnIsShape;
+
+// part 2: the second column is the outcome I have to get
+pub struct ColumnIsOutcome;
+
+impl StrategyInterpretation for ColumnIsShape {
+    fn round(&self, instruction: &Instruction) -> Round {
+        let me = match instruction.column {
+            Column::X => Shape::Rock,
+            Column::Y => Shape::Paper,
+            Column::Z => Shape::Scissors,
+        };
+
+        Round {
+            opponent: instruction.opponent,
+            me,
+        }
+    }
+}
+
+impl StrategyInterpretation for ColumnIsOutcome {
+    fn round(&self, instruction: &Instruction) -> Round {
+        let outcome: Outcome = instruction
+            .column
+            .letter()
+            .parse()
+            .expect("every column is an outcome");
+
+        Round {
+            opponent: instruction.opponent,
+            me: Shape::for_outcome(instruction.opponent, outcome),
+        }
+    }
+}
+
+// the score of following all `instructions` as read by `interpretation`
+pub fn score(instructions: &[Instruction], interpretation: &impl StrategyInterpretation) -> u32 {
+    instructions
+        .iter()
+        .map(|instruction| interpretation.round(instruction).score())
+        .sum()
+}
+
+impl Column {
+    pub fn letter(self) -> &'static str {
+        match self {
+            Column::X => "X",
+            Column::Y => "Y",
+            Column::Z => "Z",
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Column::X),
+            "Y" => Ok(Column::Y),
+            "Z" => Ok(Column::Z),
+            _ => Err(ParseError::UnknownColumn(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Instruction::parse(&Line::new(1, s)).map_err(|rejected| rejected.reason)
+    }
+}
+
+impl Instruction {
+    // like `from_str`, but the error points to the offending part of `line`
+    pub fn parse(line: &Line) -> Result<Self, RejectedLine> {
+        let (opponent, column) = columns(line)?;
+
+        Ok(Instruction {
+            opponent: opponent.parse().map_err(|e| reject(line, opponent, e))?,
+            column: column.parse().map_err(|e| reject(line, column, e))?,
+        })
+    }
+}
+
+pub(crate) fn reject(line: &Line, part: &str, reason: ParseError) -> RejectedLine {
+    RejectedLine {
+        span: line.span(part),
+        reason,
+    }
+}
+
+// the two columns of a line like "A Y"
+pub(crate) fn columns<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str), RejectedLine> {
+    line.text.split_once(' ').ok_or_else(|| {
+        reject(
+            line,
+            line.text,
+            ParseError::InvalidRound(line.text.to_string()),
+        )
+    })
+}
+
+// the instructions of `guide` and the lines that aren't rounds; blank lines are ignored
+pub fn parse_guide(guide: &str) -> (Vec<Instruction>, Vec<RejectedLine>) {
+    parse_lines(guide, Instruction::parse)
+}
+
+// the lines of `guide` read by `parse` and the lines it rejected, without blank lines
+pub(crate) fn parse_lines<T>(
+    guide: &str,
+    parse: impl Fn(&Line) -> Result<T, RejectedLine>,
+) -> (Vec<T>, Vec<RejectedLine>) {
+    let mut parsed = vec![];
+    let mut rejected = vec![];
+
+    for line in Line::all(guide).filter(|line| !line.text.trim().is_empty()) {
+        match parse(&line) {
+            Ok(value) => parsed.push(value),
+            Err(e) => rejected.push(e),
+        }
+    }
+
+    (parsed, rejected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_guide, score, Column, ColumnIsOutcome, ColumnIsShape, Instruction};
+    use crate::{ParseError, Shape};
+    use common::error::Spanned;
+
+    #[test]
+    fn instruction() {
+        assert_eq!(
+            "A Y".parse::<Instruction>(),
+            Ok(Instruction {
+                opponent: Shape::Rock,
+                column: Column::Y
+            })
+        );
+        assert_eq!(
+            "AY".parse::<Instruction>(),
+            Err(ParseError::InvalidRound("AY".to_string()))
+        );
+        assert_eq!(
+            "X Y".parse::<Instruction>(),
+            Err(ParseError::UnknownShape("X".to_string()))
+        );
+        assert_eq!(
+            "A W".parse::<Instruction>(),
+            Err(ParseError::UnknownColumn("W".to_string()))
+        );
+    }
+
+    #[test]
+    fn interpretations() {
+        let instructions: Vec<Instruction> = ["A Y", "B X", "C Z"]
+            .iter()
+            .map(|line| line.parse().unwrap())
+            .collect();
+
+        assert_eq!(score(&instructions, &ColumnIsShape), 15);
+        assert_eq!(score(&instructions, &ColumnIsOutcome), 12);
+    }
+
+    #[test]
+    fn rejected_lines() {
+        let (instructions, rejected) = parse_guide("A Y\nB X \n\nc z\nC Z\r\nAZ\n");
+
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(
+            rejected
+                .iter()
+                .map(|rejected| {
+                    let span = rejected.span();
+                    (span.line, span.column, rejected.to_string())
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    2,
+                    3,
+                    "unknown strategy 'X ', expected one of X, Y, Z".to_string()
+                ),
+                (
+                    4,
+                    1,
+                    "unknown shape 'c', expected one of A, B, C".to_string()
+                ),
+                (6, 1, "expected a round like 'A Y', got 'AZ'".to_string()),
+            ]
+        );
+    }
+}
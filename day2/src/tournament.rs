@@ -0,0 +1,285 @@
+use crate::{Outcome, Round, Shape};
+use anyhow::{bail, Context, Result};
+use common::random;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// Chooses the shapes of the opponent in a tournament; only my previous shapes are
+// known to it, not the one I am about to play.
+pub trait Opponent {
+    fn play(&mut self, my_history: &[Shape]) -> Shape;
+}
+
+// the opponent models selectable on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    // always plays the same shape
+    Fixed(Shape),
+    // plays uniformly random shapes
+    Random(u64),
+    // counters the shape I played most often so far
+    Frequency,
+    // counters the shape I played most often after my last two shapes
+    Pattern,
+}
+
+impl Model {
+    pub fn opponent(self) -> Box<dyn Opponent> {
+        match self {
+            Model::Fixed(shape) => Box::new(Fixed(shape)),
+            Model::Random(seed) => Box::new(Random(random::Random::new(seed))),
+            Model::Frequency => Box::new(Frequency),
+            Model::Pattern => Box::new(Pattern),
+        }
+    }
+
+    pub fn defaults() -> Vec<Model> {
+        let mut models: Vec<Model> = Shape::ALL.into_iter().map(Model::Fixed).collect();
+        models.extend([Model::Random(0), Model::Frequency, Model::Pattern]);
+        models
+    }
+}
+
+// models are like "fixed:rock", "random:42", "frequency" or "pattern"
+impl FromStr for Model {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once(':').unwrap_or((s, "")) {
+            ("fixed", shape) => Ok(Model::Fixed(match shape {
+                "rock" => Shape::Rock,
+                "paper" => Shape::Paper,
+                "scissors" => Shape::Scissors,
+                _ => bail!("expected 'fixed:rock', 'fixed:paper' or 'fixed:scissors', got '{s}'"),
+            })),
+            ("random", seed) => Ok(Model::Random(
+                seed.parse()
+                    .context(format!("expected a seed like 'random:42', got '{s}'"))?,
+            )),
+            ("frequency", "") => Ok(Model::Frequency),
+            ("pattern", "") => Ok(Model::Pattern),
+            _ => bail!("unknown opponent model '{s}', expected fixed:<shape>, random:<seed>, frequency or pattern"),
+        }
+    }
+}
+
+impl Display for Model {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Model::Fixed(shape) => write!(f, "fixed:{}", format!("{shape:?}").to_lowercase()),
+            Model::Random(seed) => write!(f, "random:{seed}"),
+            Model::Frequency => write!(f, "frequency"),
+            Model::Pattern => write!(f, "pattern"),
+        }
+    }
+}
+
+struct Fixed(Shape);
+
+impl Opponent for Fixed {
+    fn play(&mut self, _: &[Shape]) -> Shape {
+        self.0
+    }
+}
+
+// seeded, so that tournaments are reproducible
+struct Random(random::Random);
+
+impl Opponent for Random {
+    fn play(&mut self, _: &[Shape]) -> Shape {
+        Shape::ALL[self.0.below(Shape::ALL.len())]
+    }
+}
+
+struct Frequency;
+
+impl Opponent for Frequency {
+    fn play(&mut self, my_history: &[Shape]) -> Shape {
+        counter(most_frequent(my_history.iter().copied()))
+    }
+}
+
+struct Pattern;
+
+impl Opponent for Pattern {
+    fn play(&mut self, my_history: &[Shape]) -> Shape {
+        let Some(last) = my_history.len().checked_sub(2).map(|i| &my_history[i..]) else {
+            return Frequency.play(my_history);
+        };
+
+        let followers = my_history
+            .windows(3)
+            .filter(|window| window[..2] == *last)
+            .map(|window| window[2]);
+
+        counter(most_frequent(followers).or_else(|| most_frequent(my_history.iter().copied())))
+    }
+}
+
+// the most frequent of `shapes`, the first of them on a tie
+fn most_frequent(shapes: impl Iterator<Item = Shape>) -> Option<Shape> {
+    let mut counts = [0; 3];
+    for shape in shapes {
+        counts[shape as usize] += 1;
+    }
+
+    // `max_by_key` returns the last maximum
+    Shape::ALL
+        .into_iter()
+        .rev()
+        .filter(|shape| counts[*shape as usize] > 0)
+        .max_by_key(|shape| counts[*shape as usize])
+}
+
+// the shape beating `shape`, rock if nothing is known
+fn counter(shape: Option<Shape>) -> Shape {
+    match shape {
+        Some(shape) => Shape::for_outcome(shape, Outcome::Win),
+        None => Shape::Rock,
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: u32,
+}
+
+impl Report {
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        match self.rounds() {
+            0 => 0.0,
+            rounds => count as f64 / rounds as f64,
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.losses)
+    }
+
+    // the score to expect per round
+    pub fn expected_score(&self) -> f64 {
+        self.rate(self.score as usize)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "win {:5.1}%  draw {:5.1}%  loss {:5.1}%  score {} ({:.2} per round)",
+            self.win_rate() * 100.0,
+            self.draw_rate() * 100.0,
+            self.loss_rate() * 100.0,
+            self.score,
+            self.expected_score()
+        )
+    }
+}
+
+// plays `my_shapes` in order against `opponent`
+pub fn simulate(my_shapes: &[Shape], opponent: &mut dyn Opponent) -> Report {
+    let mut report = Report::default();
+
+    for (index, me) in my_shapes.iter().enumerate() {
+        let round = Round {
+            opponent: opponent.play(&my_shapes[..index]),
+            me: *me,
+        };
+
+        match round.outcome() {
+            Outcome::Win => report.wins += 1,
+            Outcome::Draw => report.draws += 1,
+            Outcome::Loss => report.losses += 1,
+        }
+        report.score += round.score();
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simulate, Model, Report};
+    use crate::Shape::{self, Paper, Rock, Scissors};
+
+    fn play(model: Model, my_shapes: &[Shape]) -> Report {
+        simulate(my_shapes, model.opponent().as_mut())
+    }
+
+    #[test]
+    fn fixed() {
+        let report = play(Model::Fixed(Rock), &[Rock, Paper, Scissors, Paper]);
+
+        assert_eq!(
+            report,
+            Report {
+                wins: 2,
+                draws: 1,
+                losses: 1,
+                score: (1 + 3) + (2 + 6) + 3 + (2 + 6)
+            }
+        );
+        assert_eq!(report.win_rate(), 0.5);
+        assert_eq!(report.expected_score(), 5.75);
+    }
+
+    #[test]
+    fn random_is_reproducible() {
+        let my_shapes = [Rock; 300];
+        let report = play(Model::Random(42), &my_shapes);
+
+        assert_eq!(report, play(Model::Random(42), &my_shapes));
+        assert_ne!(report, play(Model::Random(43), &my_shapes));
+        // every shape is played about a third of the time
+        for count in [report.wins, report.draws, report.losses] {
+            assert!((70..130).contains(&count), "{report:?}");
+        }
+    }
+
+    #[test]
+    fn frequency_counters_the_most_played_shape() {
+        // rock is countered with paper from the second round on
+        let report = play(Model::Frequency, &[Rock, Rock, Scissors, Rock]);
+
+        assert_eq!((report.wins, report.draws, report.losses), (1, 1, 2));
+    }
+
+    #[test]
+    fn pattern_learns_cycles() {
+        let cycle: Vec<Shape> = [Rock, Paper, Scissors]
+            .into_iter()
+            .cycle()
+            .take(30)
+            .collect();
+        let report = play(Model::Pattern, &cycle);
+
+        // once every pair of shapes has been seen, each of my shapes is countered
+        assert!(report.losses >= 25, "{report:?}");
+        assert!(play(Model::Frequency, &cycle).losses < 15);
+    }
+
+    #[test]
+    fn models() {
+        for model in Model::defaults() {
+            assert_eq!(model.to_string().parse::<Model>().unwrap(), model);
+        }
+        assert!("fixed:lizard".parse::<Model>().is_err());
+        assert!("random".parse::<Model>().is_err());
+        assert!("psychic".parse::<Model>().is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Instruction, Outcome, Shape, StrategyInterpretation};
+use std::fmt::Write;
+
+// a round of the strategy guide and how it adds up to the score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub round: usize,
+    pub opponent: Shape,
+    pub me: Shape,
+    pub outcome: Outcome,
+    pub shape_score: u32,
+    pub outcome_score: u32,
+    pub total: u32,
+}
+
+const HEADER: [&str; 7] = [
+    "round",
+    "opponent",
+    "me",
+    "outcome",
+    "shape score",
+    "outcome score",
+    "total",
+];
+
+// follows all `instructions` as read by `interpretation`, keeping a running total
+pub fn trace(
+    instructions: &[Instruction],
+    interpretation: &dyn StrategyInterpretation,
+) -> Vec<Step> {
+    let mut total = 0;
+
+    instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| {
+            let round = interpretation.round(instruction);
+            let outcome = round.outcome();
+            total += round.score();
+
+            Step {
+                round: index + 1,
+                opponent: round.opponent,
+                me: round.me,
+                outcome,
+                shape_score: round.me.score(),
+                outcome_score: outcome.score(),
+                total,
+            }
+        })
+        .collect()
+}
+
+fn fields(step: &Step) -> [String; 7] {
+    [
+        step.round.to_string(),
+        format!("{:?}", step.opponent),
+        format!("{:?}", step.me),
+        format!("{:?}", step.outcome),
+        step.shape_score.to_string(),
+        step.outcome_score.to_string(),
+        step.total.to_string(),
+    ]
+}
+
+// the steps as a table with a column per field, numbers aligned to the right
+pub fn table(steps: &[Step]) -> String {
+    let rows: Vec<[String; 7]> = steps.iter().map(fields).collect();
+    let widths: Vec<usize> = (0..HEADER.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([HEADER[column].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut table = String::new();
+    let header = HEADER.map(str::to_string);
+    for row in [&header].into_iter().chain(&rows) {
+        let mut line = String::new();
+        for (column, field) in row.iter().enumerate() {
+            let width = widths[column];
+            match column {
+                1..=3 => write!(line, "{field:<width$}  "),
+                _ => write!(line, "{field:>width$}  "),
+            }
+            .unwrap();
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+pub fn csv(steps: &[Step]) -> String {
+    let mut csv = HEADER.map(|field| field.replace(' ', "_")).join(",") + "\n";
+    for step in steps {
+        csv.push_str(&fields(step).join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv, table, trace};
+    use crate::{ColumnIsOutcome, ColumnIsShape, Instruction};
+
+    fn instructions() -> Vec<Instruction> {
+        ["A Y", "B X", "C Z"]
+            .iter()
+            .map(|line| line.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn running_total() {
+        let steps = trace(&instructions(), &ColumnIsShape);
+
+        assert_eq!(
+            steps.iter().map(|step| step.total).collect::<Vec<_>>(),
+            vec![8, 9, 15]
+        );
+        for step in &steps {
+            assert_eq!(step.shape_score, step.me.score());
+            assert_eq!(step.outcome_score, step.outcome.score());
+        }
+    }
+
+    #[test]
+    fn formats() {
+        let steps = trace(&instructions(), &ColumnIsOutcome);
+
+        assert_eq!(
+            table(&steps),
+            "round  opponent  me    outcome  shape score  outcome score  total
+    1  Rock      Rock  Draw               1              3      4
+    2  Paper     Rock  Loss               1              0      5
+    3  Scissors  Rock  Win                1              6     12
+"
+        );
+        assert_eq!(
+            csv(&steps),
+            "round,opponent,me,outcome,shape_score,outcome_score,total
+1,Rock,Rock,Draw,1,3,4
+2,Paper,Rock,Loss,1,0,5
+3,Scissors,Rock,Win,1,6,12
+"
+        );
+    }
+}
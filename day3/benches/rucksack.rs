@@ -0,0 +1,95 @@
+use common::random::Random;
+use common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day3::{calc_priority, Day3, Letters};
+use std::collections::HashSet;
+use std::hint::black_box;
+
+const ITEMS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Groups of three rucksacks, each with exactly one item in both compartments and
+// exactly one badge per group: the items besides the badge are split between the
+// members of a group so that no other item is shared.
+fn generate(groups: usize, compartment: usize) -> String {
+    // seeded, so that the generated inputs are the same for every run
+    let mut random = Random::new(42);
+    let mut input = String::new();
+
+    for _ in 0..groups {
+        let badge = random.below(ITEMS.len());
+        let others: Vec<u8> = (0..ITEMS.len())
+            .filter(|item| *item != badge)
+            .map(|item| ITEMS[item])
+            .collect();
+
+        for member in others.chunks(others.len() / 3).take(3) {
+            let common = member[0];
+            let (first, second) = member[1..].split_at((member.len() - 1) / 2);
+
+            let mut line = vec![common, ITEMS[badge]];
+            line.extend((2..compartment).map(|_| first[random.below(first.len())]));
+            line.push(common);
+            line.extend((1..compartment).map(|_| second[random.below(second.len())]));
+            input.push_str(std::str::from_utf8(&line).unwrap());
+            input.push('\n');
+        }
+    }
+
+    input
+}
+
+// the previous implementation building hash sets for every rucksack
+fn hash_sets(input: &str) -> (u32, u32) {
+    let rucksacks: Vec<String> = input.lines().map(String::from).collect();
+
+    let mut part1 = 0;
+    for line in &rucksacks {
+        let (first, second) = line.split_at(line.len() / 2);
+        let first: HashSet<char> = HashSet::from_iter(first.chars());
+        let second: HashSet<char> = HashSet::from_iter(second.chars());
+        part1 += calc_priority(first.intersection(&second).last().unwrap()) as u32;
+    }
+
+    let mut part2 = 0;
+    for group in rucksacks.chunks_exact(3) {
+        let first: HashSet<char> = HashSet::from_iter(group[0].chars());
+        let second: HashSet<char> = HashSet::from_iter(group[1].chars());
+        let third: HashSet<char> = HashSet::from_iter(group[2].chars());
+        let common: String = first.intersection(&second).collect();
+        let common: HashSet<char> = HashSet::from_iter(common.chars());
+        part2 += calc_priority(common.intersection(&third).last().unwrap()) as u32;
+    }
+
+    (part1, part2)
+}
+
+fn bit_sets(input: &str) -> (u32, u32) {
+    let rucksacks = Day3::parse(input).unwrap();
+
+    (
+        day3::part1(&rucksacks, &Letters).unwrap(),
+        day3::part2(&rucksacks, &Letters).unwrap(),
+    )
+}
+
+fn rucksacks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rucksacks");
+    group.sample_size(10);
+
+    for groups in [1_000, 100_000] {
+        let input = generate(groups, 16);
+        assert_eq!(hash_sets(&input), bit_sets(&input));
+
+        group.bench_with_input(BenchmarkId::new("hash sets", groups), &input, |b, input| {
+            b.iter(|| hash_sets(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("bit sets", groups), &input, |b, input| {
+            b.iter(|| bit_sets(black_box(input)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, rucksacks);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+use common::error::{Span, Spanned};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("the compartments of rucksack '{}' have no item in common", .0.text)]
+    NoCommonItem(Span),
+    #[error("the group starting with rucksack '{}' has no badge in common", .0.text)]
+    NoBadge(Span),
+    #[error("the compartments of rucksack '{}' have the items '{items}' in common", .span.text)]
+    CommonItems { span: Span, items: String },
+    #[error("the group starting with rucksack '{}' has the badges '{items}' in common", .span.text)]
+    Badges { span: Span, items: String },
+    #[error("rucksack '{}' has an odd number of items, {}", .0.text, .0.text.chars().count())]
+    OddLength(Span),
+    #[error("'{}' is not an item", .0.text)]
+    InvalidItem(Span),
+    #[error("the items of rucksack '{}' don't fit into two compartments of the same size without sharing one", .0.text)]
+    CannotRepack(Span),
+}
+
+impl Spanned for Error {
+    fn span(&self) -> &Span {
+        match self {
+            Error::NoCommonItem(span)
+            | Error::NoBadge(span)
+            | Error::CommonItems { span, .. }
+            | Error::Badges { span, .. }
+            | Error::OddLength(span)
+            | Error::InvalidItem(span)
+            | Error::CannotRepack(span) => span,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{Error, PriorityScheme, Rucksack};
+use clap::ValueEnum;
+use log::trace;
+use std::num::NonZeroUsize;
+
+// the doc comments are the help of `--grouping`
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupMode {
+    /// Every rucksack belongs to one group of consecutive rucksacks
+    #[default]
+    Consecutive,
+    /// Every run of consecutive rucksacks is a group, so groups overlap
+    Sliding,
+}
+
+// how the rucksacks are grouped to find badges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grouping {
+    pub size: NonZeroUsize,
+    pub mode: GroupMode,
+}
+
+impl Default for Grouping {
+    fn default() -> Self {
+        Grouping {
+            size: NonZeroUsize::new(3).unwrap(),
+            mode: GroupMode::Consecutive,
+        }
+    }
+}
+
+impl Grouping {
+    pub fn groups<'a>(
+        &self,
+        rucksacks: &'a [Rucksack],
+    ) -> Box<dyn Iterator<Item = &'a [Rucksack]> + 'a> {
+        match self.mode {
+            GroupMode::Consecutive => Box::new(rucksacks.chunks_exact(self.size.get())),
+            GroupMode::Sliding => Box::new(rucksacks.windows(self.size.get())),
+        }
+    }
+
+    // the rucksacks at the end that are too few for a group of their own, they
+    // aren't part of any group
+    pub fn incomplete<'a>(&self, rucksacks: &'a [Rucksack]) -> Option<&'a [Rucksack]> {
+        let incomplete = match self.mode {
+            GroupMode::Consecutive => rucksacks.chunks_exact(self.size.get()).remainder(),
+            GroupMode::Sliding if rucksacks.len() < self.size.get() => rucksacks,
+            GroupMode::Sliding => &[],
+        };
+
+        (!incomplete.is_empty()).then_some(incomplete)
+    }
+}
+
+// the priority of the only item all rucksacks of `group` have
+pub fn badge(group: &[Rucksack], scheme: &dyn PriorityScheme) -> Result<u8, Error> {
+    let badges = Rucksack::intersect_group(group);
+    match badges.len() {
+        0 => Err(Error::NoBadge(group[0].span.clone())),
+        1 => Ok(badges.first().unwrap()),
+        _ => Err(Error::Badges {
+            span: group[0].span.clone(),
+            items: badges.items(scheme),
+        }),
+    }
+}
+
+// The sum of the priorities of the badges of all groups. Consecutive groups have
+// to have exactly one badge each; sliding groups overlap groups of different elves,
+// so the ones without exactly one badge are skipped (see `skipped`).
+pub fn badges(
+    rucksacks: &[Rucksack],
+    grouping: &Grouping,
+    scheme: &dyn PriorityScheme,
+) -> Result<u32, Error> {
+    let mut result = 0;
+
+    for group in grouping.groups(rucksacks) {
+        let priority = match (badge(group, scheme), grouping.mode) {
+            (Ok(priority), _) => priority,
+            (Err(_), GroupMode::Sliding) => continue,
+            (Err(e), GroupMode::Consecutive) => return Err(e),
+        };
+        trace!(
+            "group starting at rucksack {}: badge with priority {priority}",
+            group[0].span.line
+        );
+
+        result += priority as u32;
+    }
+
+    Ok(result)
+}
+
+// the sliding groups `badges` skips, because they don't have exactly one badge
+pub fn skipped(
+    rucksacks: &[Rucksack],
+    grouping: &Grouping,
+    scheme: &dyn PriorityScheme,
+) -> Vec<Error> {
+    match grouping.mode {
+        GroupMode::Consecutive => vec![],
+        GroupMode::Sliding => grouping
+            .groups(rucksacks)
+            .filter_map(|group| badge(group, scheme).err())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{badges, skipped, GroupMode, Grouping};
+    use crate::{Day3, Error, Letters, Rucksack};
+    use common::Solution;
+    use std::num::NonZeroUsize;
+
+    fn rucksacks(input: &str) -> Vec<Rucksack> {
+        Day3::parse(input).unwrap()
+    }
+
+    fn grouping(size: usize, mode: GroupMode) -> Grouping {
+        Grouping {
+            size: NonZeroUsize::new(size).unwrap(),
+            mode,
+        }
+    }
+
+    fn lines(rucksacks: Option<&[Rucksack]>) -> Vec<usize> {
+        rucksacks
+            .unwrap_or_default()
+            .iter()
+            .map(|rucksack| rucksack.span.line)
+            .collect()
+    }
+
+    #[test]
+    fn consecutive() {
+        let rucksacks = rucksacks("abab\nacac\nadad\naeae\nbfbf\n");
+
+        assert_eq!(
+            badges(&rucksacks, &grouping(2, GroupMode::Consecutive), &Letters),
+            Ok(2)
+        );
+        assert_eq!(
+            lines(grouping(2, GroupMode::Consecutive).incomplete(&rucksacks)),
+            vec![5]
+        );
+        assert_eq!(
+            badges(&rucksacks, &grouping(4, GroupMode::Consecutive), &Letters),
+            Ok(1)
+        );
+        assert_eq!(
+            badges(&rucksacks, &grouping(5, GroupMode::Consecutive), &Letters)
+                .map_err(|e| e.to_string()),
+            Err("the group starting with rucksack 'abab' has no badge in common".to_string())
+        );
+        assert_eq!(
+            grouping(5, GroupMode::Consecutive).incomplete(&rucksacks),
+            None
+        );
+        assert!(matches!(
+            badges(
+                &rucksacks[..1],
+                &grouping(1, GroupMode::Consecutive),
+                &Letters
+            ),
+            Err(Error::Badges { .. })
+        ));
+    }
+
+    #[test]
+    fn sliding() {
+        let rucksacks = rucksacks("abab\nacac\nadad\nbebe\n");
+        let sliding = grouping(2, GroupMode::Sliding);
+
+        assert_eq!(
+            sliding
+                .groups(&rucksacks)
+                .map(|group| group[0].span.line)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        // the last group has no badge and is skipped
+        assert_eq!(badges(&rucksacks, &sliding, &Letters), Ok(2));
+        assert!(matches!(
+            skipped(&rucksacks, &sliding, &Letters)[..],
+            [Error::NoBadge(ref span)] if span.line == 3
+        ));
+        assert_eq!(badges(&rucksacks[..3], &sliding, &Letters), Ok(2));
+        assert!(skipped(&rucksacks[..3], &sliding, &Letters).is_empty());
+        assert!(skipped(&rucksacks, &grouping(2, GroupMode::Consecutive), &Letters).is_empty());
+        assert_eq!(sliding.incomplete(&rucksacks), None);
+        assert_eq!(
+            lines(grouping(5, GroupMode::Sliding).incomplete(&rucksacks)),
+            vec![1, 2, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::Result;
+use common::error::{Diagnostic, Line};
+use common::Solution;
+pub use error::Error;
+pub use group::{badge, badges, skipped, GroupMode, Grouping};
+use log::{debug, trace};
+pub use priority::{calc_priority, Letters, PriorityScheme, Table, MAX_PRIORITY};
+pub use repack::{repack, Compartment, Move, Repacking};
+pub use rucksack::{ItemSet, Rucksack};
+pub use validate::{validate, Check, Verdict};
+
+mod error;
+mod group;
+mod priority;
+mod repack;
+mod rucksack;
+mod validate;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input, &Letters).map_err(Diagnostic::from)?)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Self::Output> {
+        Ok(part1(rucksacks, &Letters).map_err(Diagnostic::from)?)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Self::Output> {
+        Ok(part2(rucksacks, &Letters).map_err(Diagnostic::from)?)
+    }
+}
+
+pub fn parse(input: &str, scheme: &dyn PriorityScheme) -> Result<Vec<Rucksack>, Error> {
+    let rucksacks = Line::all(input)
+        .map(|line| Rucksack::parse(&line, scheme))
+        .collect::<Result<Vec<_>, _>>()?;
+    debug!("parsed {} rucksacks", rucksacks.len());
+
+    Ok(rucksacks)
+}
+
+pub fn part1(rucksacks: &[Rucksack], scheme: &dyn PriorityScheme) -> Result<u32, Error> {
+    let mut result = 0;
+
+    for rucksack in rucksacks {
+        let priority = rucksack.common_item(scheme)?;
+        trace!(
+            "rucksack {}: common item with priority {priority}",
+            rucksack.span.line
+        );
+
+        result += priority as u32;
+    }
+
+    Ok(result)
+}
+
+pub fn part2(rucksacks: &[Rucksack], scheme: &dyn PriorityScheme) -> Result<u32, Error> {
+    badges(rucksacks, &Grouping::default(), scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day3, Error, Letters};
+    use common::error::Spanned;
+    use common::Solution;
+
+    #[test]
+    fn part1() {
+        let rucksacks = Day3::parse(
+            r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+"#,
+        )
+        .unwrap();
+
+        let result = super::part1(&rucksacks, &Letters).unwrap();
+        assert_eq!(result, 157);
+    }
+
+    #[test]
+    fn part2() {
+        let rucksacks = Day3::parse(
+            r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+"#,
+        )
+        .unwrap();
+
+        let result = super::part2(&rucksacks, &Letters).unwrap();
+        assert_eq!(result, 70);
+    }
+
+    #[test]
+    fn errors() {
+        let rucksacks = Day3::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabcdef\n").unwrap();
+        let err = super::part1(&rucksacks, &Letters).unwrap_err();
+        assert!(matches!(err, Error::NoCommonItem(_)));
+        assert_eq!(err.span().line, 2);
+
+        let rucksacks = Day3::parse("abab\ncdcd\nefef\n").unwrap();
+        assert!(matches!(
+            super::part2(&rucksacks, &Letters),
+            Err(Error::NoBadge(_))
+        ));
+
+        let err = Day3::parse("abab\nab-ab\n").unwrap_err();
+        assert!(err.to_string().contains("'-'"), "{err}");
+    }
+}
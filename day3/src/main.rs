@@ -1,129 +1,133 @@
 use anyhow::{Context, Result};
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Seek};
-
-fn main() -> Result<()> {
-    let file_name = "input";
-    let mut file =
-        File::open(&file_name).context(format!("while opening file '{}'", &file_name))?;
-
-    println!("part1: {}", part1(BufReader::new(&file))?);
-
-    file.rewind()?;
-    println!("part2: {}", part2(BufReader::new(&file))?);
-    Ok(())
-}
-
-fn part1<T>(reader: BufReader<T>) -> Result<u32>
-where
-    T: std::io::Read,
-{
-    let result: u32 = reader.lines().fold(0, |acc, line| {
-        let line = line.unwrap();
-        let (first_compartment, second_compartment) = line.as_str().split_at(&line.len() / 2);
-
-        let first_compartment_items: HashSet<char> = HashSet::from_iter(first_compartment.chars());
-        let second_compartment_items: HashSet<char> =
-            HashSet::from_iter(second_compartment.chars());
-
-        let common_item: &char = first_compartment_items
-            .intersection(&second_compartment_items)
-            .last()
-            .unwrap();
-
-        let priority = calc_priority(common_item);
-
-        acc + (priority as u32)
-    });
-
-    Ok(result)
+use clap::Parser;
+use common::cli::Args;
+use common::error::{annotate, Diagnostic, Spanned};
+use common::Solution;
+use day3::{
+    badges, part1, repack, skipped, validate, Day3, GroupMode, Grouping, Letters, PriorityScheme,
+    Table,
+};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+
+    /// Read the items and their priorities from this file instead of using a-z and A-Z
+    #[arg(long, value_name = "FILE")]
+    priorities: Option<PathBuf>,
+
+    /// Number of rucksacks sharing a badge
+    #[arg(long, default_value = "3")]
+    group_size: NonZeroUsize,
+
+    /// How the rucksacks are grouped
+    #[arg(long, value_enum, default_value_t)]
+    grouping: GroupMode,
+
+    /// Report the rucksacks at the end that are too few for a group instead of ignoring them
+    #[arg(long)]
+    report_incomplete: bool,
+
+    /// Check every rucksack and print what is wrong with it instead of the answers
+    #[arg(long, conflicts_with_all = ["group_size", "grouping", "report_incomplete"])]
+    validate: bool,
+
+    /// Suggest the fewest item moves so that no item is in both compartments instead of the answers
+    #[arg(long, conflicts_with_all = ["group_size", "grouping", "report_incomplete", "validate"])]
+    repack: bool,
 }
 
-fn part2<T>(reader: BufReader<T>) -> Result<u32>
-where
-    T: std::io::Read,
-{
-    let mut iterator = reader.lines();
-
-    let mut result = 0;
-
-    loop {
-        match (iterator.next(), iterator.next(), iterator.next()) {
-            (Some(Ok(first_rucksack)), Some(Ok(second_rucksack)), Some(Ok(third_rucksack))) => {
-                let first_rucksack_items: HashSet<char> =
-                    HashSet::from_iter(first_rucksack.chars());
-                let second_rucksack_items: HashSet<char> =
-                    HashSet::from_iter(second_rucksack.chars());
-                let third_rucksack_items: HashSet<char> =
-                    HashSet::from_iter(third_rucksack.chars());
-
-                let first_and_second_common: String = first_rucksack_items
-                    .intersection(&second_rucksack_items)
-                    .collect();
-
-                let first_and_second_common_items =
-                    HashSet::from_iter(first_and_second_common.chars());
-
-                let common_group_item = first_and_second_common_items
-                    .intersection(&third_rucksack_items)
-                    .last()
-                    .unwrap();
-
-                let priority = calc_priority(common_group_item);
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    cli.args.logging.init();
+
+    let (source, input) = cli.args.load(Day3::DAY)?;
+    let scheme: Box<dyn PriorityScheme> = match &cli.priorities {
+        Some(path) => Box::new(Table::load(path)?),
+        None => Box::new(Letters),
+    };
+    let scheme = scheme.as_ref();
+
+    if cli.validate {
+        let checks = validate(&input, scheme);
+        let invalid: Vec<_> = checks
+            .iter()
+            .filter(|check| !check.verdict.is_well_formed())
+            .collect();
+        for check in &invalid {
+            println!("line {}: {}", check.line, check.verdict);
+        }
+        println!(
+            "{} of {} rucksacks are well-formed",
+            checks.len() - invalid.len(),
+            checks.len()
+        );
+        return Ok(());
+    }
 
-                result += priority as u32;
+    let rucksacks = day3::parse(&input, scheme)
+        .map_err(|e| annotate(Diagnostic::from(e).into(), &input))
+        .context(format!("while parsing '{}'", source))?;
+
+    if cli.repack {
+        let (mut rucksacks_moved, mut items_moved, mut saved) = (0, 0, 0);
+        for rucksack in &rucksacks {
+            match repack(rucksack, scheme) {
+                Ok(repacking) if repacking.moves.is_empty() => {}
+                Ok(repacking) => {
+                    println!("{repacking}");
+                    rucksacks_moved += 1;
+                    items_moved += repacking.items_moved();
+                    saved += repacking.saved;
+                }
+                Err(e) => println!("line {}: {e}", rucksack.span.line),
             }
-            _ => break,
         }
+        println!("moving {items_moved} items in {rucksacks_moved} rucksacks saves {saved}");
+        return Ok(());
     }
 
-    Ok(result)
-}
-
-fn calc_priority(duplicate: &char) -> u8 {
-    match *duplicate {
-        c if c >= 'a' && c <= 'z' => c as u8 - 'a' as u8 + 1,
-        c if c >= 'A' && c <= 'Z' => c as u8 - 'A' as u8 + 27,
-        _ => 0,
+    let grouping = Grouping {
+        size: cli.group_size,
+        mode: cli.grouping,
+    };
+    if let Some(incomplete) = grouping
+        .incomplete(&rucksacks)
+        .filter(|_| cli.report_incomplete)
+    {
+        eprintln!(
+            "{source}: lines {}-{} are {} rucksacks, too few for a group of {}",
+            incomplete[0].span.line,
+            incomplete[incomplete.len() - 1].span.line,
+            incomplete.len(),
+            grouping.size
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::io::BufReader;
-
-    #[test]
-    fn part1() {
-        let reader = BufReader::new(
-            r#"vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-"#
-            .as_bytes(),
+    let skipped = skipped(&rucksacks, &grouping, scheme);
+    for error in &skipped {
+        eprintln!("{source}: skipping line {}: {error}", error.span().line);
+    }
+    if !skipped.is_empty() {
+        eprintln!(
+            "{source}: skipped {} of {} groups",
+            skipped.len(),
+            grouping.groups(&rucksacks).count()
         );
-
-        let result = super::part1(reader).unwrap();
-        assert_eq!(result, 157);
     }
 
-    #[test]
-    fn part2() {
-        let reader = BufReader::new(
-            r#"vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-"#
-            .as_bytes(),
-        );
+    println!(
+        "part1: {}",
+        part1(&rucksacks, scheme).map_err(|e| annotate(Diagnostic::from(e).into(), &input))?
+    );
+    println!(
+        "part2: {}",
+        badges(&rucksacks, &grouping, scheme)
+            .map_err(|e| annotate(Diagnostic::from(e).into(), &input))?
+    );
 
-        let result = super::part2(reader).unwrap();
-        assert_eq!(result, 70);
-    }
+    Ok(())
 }
@@ -0,0 +1,149 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+// the highest priority an `ItemSet` can hold
+pub const MAX_PRIORITY: u8 = 64;
+
+// Which characters are items and what their priorities are; every item has its
+// own priority between 1 and `MAX_PRIORITY`.
+pub trait PriorityScheme {
+    // none if `item` isn't an item
+    fn priority(&self, item: char) -> Option<u8>;
+
+    // the item with `priority`, the inverse of `priority`
+    fn item(&self, priority: u8) -> Option<char>;
+}
+
+// the scheme of the puzzle: a-z are 1 to 26, A-Z are 27 to 52
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Letters;
+
+impl PriorityScheme for Letters {
+    fn priority(&self, item: char) -> Option<u8> {
+        match calc_priority(&item) {
+            0 => None,
+            priority => Some(priority),
+        }
+    }
+
+    fn item(&self, priority: u8) -> Option<char> {
+        match priority {
+            1..=26 => Some((b'a' + priority - 1) as char),
+            27..=52 => Some((b'A' + priority - 27) as char),
+            _ => None,
+        }
+    }
+}
+
+pub fn calc_priority(duplicate: &char) -> u8 {
+    match *duplicate {
+        c @ 'a'..='z' => c as u8 - b'a' + 1,
+        c @ 'A'..='Z' => c as u8 - b'A' + 27,
+        _ => 0,
+    }
+}
+
+// a scheme listing the priority of every item, read from a file like
+//
+//     [priorities]
+//     "0" = 1
+//     "1" = 2
+#[derive(Debug, Clone)]
+pub struct Table {
+    priorities: HashMap<char, u8>,
+    items: HashMap<u8, char>,
+}
+
+#[derive(Deserialize)]
+struct TableFile {
+    priorities: HashMap<String, u8>,
+}
+
+impl Table {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let table =
+            fs::read_to_string(path).context(format!("while opening file '{}'", path.display()))?;
+        Self::parse(&table).context(format!("while parsing priorities '{}'", path.display()))
+    }
+
+    pub fn parse(table: &str) -> Result<Self> {
+        let file: TableFile = toml::from_str(table)?;
+
+        let mut priorities = HashMap::new();
+        let mut items = HashMap::new();
+        for (item, priority) in file.priorities {
+            let mut chars = item.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                bail!("expected a single character as item, got '{item}'")
+            };
+            if !(1..=MAX_PRIORITY).contains(&priority) {
+                bail!("the priority of '{item}' has to be between 1 and {MAX_PRIORITY}, got {priority}")
+            }
+            if let Some(other) = items.insert(priority, c) {
+                bail!("'{other}' and '{item}' both have priority {priority}")
+            }
+            priorities.insert(c, priority);
+        }
+
+        Ok(Table { priorities, items })
+    }
+}
+
+impl PriorityScheme for Table {
+    fn priority(&self, item: char) -> Option<u8> {
+        self.priorities.get(&item).copied()
+    }
+
+    fn item(&self, priority: u8) -> Option<char> {
+        self.items.get(&priority).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Letters, PriorityScheme, Table};
+
+    #[test]
+    fn letters() {
+        assert_eq!(Letters.priority('p'), Some(16));
+        assert_eq!(Letters.priority('L'), Some(38));
+        assert_eq!(Letters.priority('1'), None);
+        for priority in 1..=52 {
+            let item = Letters.item(priority).unwrap();
+            assert_eq!(Letters.priority(item), Some(priority));
+        }
+        assert_eq!(Letters.item(53), None);
+    }
+
+    #[test]
+    fn tables() {
+        let letters = Table::parse(include_str!("../priorities/letters.toml")).unwrap();
+        for item in ('a'..='z').chain('A'..='Z').chain(['1', 'é']) {
+            assert_eq!(letters.priority(item), Letters.priority(item));
+        }
+
+        let greek = Table::parse(include_str!("../priorities/greek.toml")).unwrap();
+        assert_eq!(greek.priority('α'), Some(1));
+        assert_eq!(greek.item(48), Some('Ω'));
+        assert_eq!(greek.priority('a'), None);
+    }
+
+    #[test]
+    fn invalid_tables() {
+        let error = |table: &str| Table::parse(table).unwrap_err().to_string();
+
+        assert_eq!(
+            error("[priorities]\nab = 1\n"),
+            "expected a single character as item, got 'ab'"
+        );
+        assert_eq!(
+            error("[priorities]\na = 65\n"),
+            "the priority of 'a' has to be between 1 and 64, got 65"
+        );
+        assert!(error("[priorities]\na = 1\nb = 1\n").ends_with("both have priority 1"));
+    }
+}
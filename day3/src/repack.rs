@@ -0,0 +1,265 @@
+use crate::{Error, PriorityScheme, Rucksack, MAX_PRIORITY};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+// `count` items `item` moved out of compartment `from` into the other one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    pub count: usize,
+    pub from: Compartment,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repacking {
+    pub line: usize,
+    pub moves: Vec<Move>,
+    // the priorities of the items that were in both compartments
+    pub saved: u32,
+    // the rucksack after the moves, moved items come last in their new compartment
+    pub packed: String,
+}
+
+impl Repacking {
+    pub fn items_moved(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+}
+
+// The fewest items to move between the compartments of `rucksack` so that no item
+// is in both of them while they keep the same size.
+//
+// Every item ends up in one compartment with all its copies, so this picks the
+// items for the first compartment whose copies add up to half of the rucksack, as
+// cheap as possible: a knapsack over the item types.
+pub fn repack(rucksack: &Rucksack, scheme: &dyn PriorityScheme) -> Result<Repacking, Error> {
+    let items = rucksack.priorities();
+    if !items.len().is_multiple_of(2) {
+        return Err(Error::OddLength(rucksack.span.clone()));
+    }
+    let half = items.len() / 2;
+
+    // the number of copies of every item in both compartments
+    let mut counts = [[0; 2]; MAX_PRIORITY as usize];
+    for (index, priority) in items.iter().enumerate() {
+        counts[*priority as usize - 1][(index >= half) as usize] += 1;
+    }
+    let types: Vec<(u8, [usize; 2])> = (1..=MAX_PRIORITY)
+        .map(|priority| (priority, counts[priority as usize - 1]))
+        .filter(|(_, [first, second])| first + second > 0)
+        .collect();
+
+    // the fewest moves putting `size` items into the first compartment, and for every
+    // type the compartment it went to on the way to each size
+    let mut moves: Vec<Option<usize>> = vec![None; items.len() + 1];
+    moves[0] = Some(0);
+    let mut choices = Vec::with_capacity(types.len());
+    for (_, [first, second]) in &types {
+        let mut next: Vec<Option<usize>> = vec![None; items.len() + 1];
+        let mut choice = vec![Compartment::Second; items.len() + 1];
+
+        for (size, count) in moves.iter().enumerate() {
+            let Some(count) = count else { continue };
+            let mut relax = |size: usize, count: usize, compartment| {
+                if next[size].is_none_or(|best| count < best) {
+                    next[size] = Some(count);
+                    choice[size] = compartment;
+                }
+            };
+
+            relax(size, count + first, Compartment::Second);
+            if size + first + second <= items.len() {
+                relax(size + first + second, count + second, Compartment::First);
+            }
+        }
+
+        moves = next;
+        choices.push(choice);
+    }
+    if moves[half].is_none() {
+        return Err(Error::CannotRepack(rucksack.span.clone()));
+    }
+
+    let mut compartments = [Compartment::Second; MAX_PRIORITY as usize];
+    let mut size = half;
+    for ((priority, [first, second]), choice) in types.iter().zip(&choices).rev() {
+        compartments[*priority as usize - 1] = choice[size];
+        if choice[size] == Compartment::First {
+            size -= first + second;
+        }
+    }
+
+    let compartment = |priority: u8| compartments[priority as usize - 1];
+    let item = |priority: u8| scheme.item(priority).unwrap_or_default();
+    let moves = types
+        .iter()
+        .filter_map(|(priority, [first, second])| match compartment(*priority) {
+            Compartment::First if *second > 0 => Some(Move {
+                item: item(*priority),
+                count: *second,
+                from: Compartment::Second,
+            }),
+            Compartment::Second if *first > 0 => Some(Move {
+                item: item(*priority),
+                count: *first,
+                from: Compartment::First,
+            }),
+            _ => None,
+        })
+        .collect();
+    let saved = types
+        .iter()
+        .filter(|(_, [first, second])| *first > 0 && *second > 0)
+        .map(|(priority, _)| *priority as u32)
+        .sum();
+
+    let (first, second) = items.split_at(half);
+    let packed = |to: Compartment, kept: &[u8], moved: &[u8]| {
+        kept.iter()
+            .chain(moved)
+            .filter(|priority| compartment(**priority) == to)
+            .map(|priority| item(*priority))
+            .collect::<String>()
+    };
+
+    Ok(Repacking {
+        line: rucksack.span.line,
+        moves,
+        saved,
+        packed: packed(Compartment::First, first, second)
+            + &packed(Compartment::Second, second, first),
+    })
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let to = match self.from {
+            Compartment::First => "second",
+            Compartment::Second => "first",
+        };
+        write!(
+            f,
+            "{} x '{}' into the {to} compartment",
+            self.count, self.item
+        )
+    }
+}
+
+impl Display for Repacking {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let moves: Vec<String> = self.moves.iter().map(Move::to_string).collect();
+        write!(
+            f,
+            "line {}: move {}, saving {}: {}",
+            self.line,
+            moves.join(", "),
+            self.saved,
+            self.packed
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{repack, Repacking};
+    use crate::{Day3, Error, Letters, Rucksack, Table};
+    use common::error::Line;
+    use common::Solution;
+
+    fn repacked(text: &str) -> Result<Repacking, Error> {
+        repack(
+            &Rucksack::parse(&Line::new(1, text), &Letters).unwrap(),
+            &Letters,
+        )
+    }
+
+    // the compartments don't share an item and hold the items of `text`
+    fn assert_packed(text: &str, repacking: &Repacking) {
+        let (first, second) = repacking.packed.split_at(text.len() / 2);
+        assert!(
+            !first.chars().any(|item| second.contains(item)),
+            "{repacking}"
+        );
+
+        let sorted = |text: &str| {
+            let mut items: Vec<char> = text.chars().collect();
+            items.sort();
+            items
+        };
+        assert_eq!(sorted(text), sorted(&repacking.packed));
+    }
+
+    #[test]
+    fn moves() {
+        let repacking = repacked("abcb").unwrap();
+
+        // one 'b' has to go, and something else has to take its place
+        assert_eq!(repacking.items_moved(), 2);
+        assert_eq!(repacking.saved, 2);
+        assert_packed("abcb", &repacking);
+
+        // both 'b's of one compartment move over, and two other items back
+        let repacking = repacked("abbcdbbe").unwrap();
+        assert!(repacking
+            .moves
+            .iter()
+            .any(|m| m.item == 'b' && m.count == 2));
+        assert_eq!(repacking.items_moved(), 4);
+        assert_packed("abbcdbbe", &repacking);
+    }
+
+    #[test]
+    fn nothing_to_move() {
+        let repacking = repacked("abcd").unwrap();
+
+        assert_eq!(repacking.moves, vec![]);
+        assert_eq!(repacking.saved, 0);
+        assert_eq!(repacking.packed, "abcd");
+    }
+
+    #[test]
+    fn impossible() {
+        // three 'a's can't be in one compartment of two items
+        assert!(matches!(repacked("aaab"), Err(Error::CannotRepack(_))));
+        assert!(matches!(repacked("abcab"), Err(Error::OddLength(_))));
+    }
+
+    #[test]
+    fn other_schemes() {
+        // repacks the items the scheme's parser read, whatever characters they are
+        let greek = Table::parse(include_str!("../priorities/greek.toml")).unwrap();
+        let rucksack = Rucksack::parse(&Line::new(1, "αΩβΩ"), &greek).unwrap();
+        let repacking = repack(&rucksack, &greek).unwrap();
+
+        assert_eq!(repacking.items_moved(), 2);
+        assert_eq!(repacking.saved, 48);
+        assert_packed("αΩβΩ", &repacking);
+    }
+
+    #[test]
+    fn saves_part1() {
+        let rucksacks = Day3::parse(
+            r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+"#,
+        )
+        .unwrap();
+
+        let mut saved = 0;
+        for rucksack in &rucksacks {
+            let repacking = repack(rucksack, &Letters).unwrap();
+            assert_packed(&rucksack.span.text, &repacking);
+            saved += repacking.saved;
+        }
+        assert_eq!(saved, 157);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{Error, PriorityScheme, MAX_PRIORITY};
+use common::error::{Line, Span};
+
+// A set of items: bit `priority - 1` stands for the item with that priority, so
+// up to 64 items fit into a `u64`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, priority: u8) {
+        self.0 |= 1 << (priority - 1);
+    }
+
+    pub fn contains(self, priority: u8) -> bool {
+        self.0 & (1 << (priority - 1)) != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    // the lowest priority in the set
+    pub fn first(self) -> Option<u8> {
+        match self.0 {
+            0 => None,
+            bits => Some(bits.trailing_zeros() as u8 + 1),
+        }
+    }
+
+    // the priorities in the set, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = u8> {
+        (1..=MAX_PRIORITY).filter(move |priority| self.contains(*priority))
+    }
+
+    pub fn items(self, scheme: &dyn PriorityScheme) -> String {
+        self.priorities()
+            .filter_map(|priority| scheme.item(priority))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    // the whole line of the rucksack
+    pub span: Span,
+    first: ItemSet,
+    second: ItemSet,
+    // the priorities of all items in the order of the line
+    priorities: Vec<u8>,
+}
+
+impl Rucksack {
+    // the first half of the line is the first compartment, the rest the second one
+    pub fn parse(line: &Line, scheme: &dyn PriorityScheme) -> Result<Self, Error> {
+        let mut items = ItemSet::EMPTY;
+        let mut first = ItemSet::EMPTY;
+        let mut priorities = Vec::with_capacity(line.text.len());
+        let half = line.text.chars().count() / 2;
+
+        for (index, (offset, item)) in line.text.char_indices().enumerate() {
+            // other schemes may have priorities an `ItemSet` can't hold
+            let priority = scheme
+                .priority(item)
+                .filter(|priority| (1..=MAX_PRIORITY).contains(priority));
+            let Some(priority) = priority else {
+                let item = &line.text[offset..offset + item.len_utf8()];
+                return Err(Error::InvalidItem(line.span(item)));
+            };
+            if index == half {
+                first = items;
+                items = ItemSet::EMPTY;
+            }
+            items.insert(priority);
+            priorities.push(priority);
+        }
+
+        Ok(Rucksack {
+            span: line.whole(),
+            first,
+            second: items,
+            priorities,
+        })
+    }
+
+    // the priorities of the items, the first half is the first compartment
+    pub fn priorities(&self) -> &[u8] {
+        &self.priorities
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.first.union(self.second)
+    }
+
+    // the items in both compartments
+    pub fn common_items(&self) -> ItemSet {
+        self.first.intersection(self.second)
+    }
+
+    // the priority of the only item in both compartments
+    pub fn common_item(&self, scheme: &dyn PriorityScheme) -> Result<u8, Error> {
+        if !self.priorities.len().is_multiple_of(2) {
+            return Err(Error::OddLength(self.span.clone()));
+        }
+
+        let items = self.common_items();
+        match items.len() {
+            0 => Err(Error::NoCommonItem(self.span.clone())),
+            1 => Ok(items.first().unwrap()),
+            _ => Err(Error::CommonItems {
+                span: self.span.clone(),
+                items: items.items(scheme),
+            }),
+        }
+    }
+
+    // the items all rucksacks of `group` have
+    pub fn intersect_group(group: &[Rucksack]) -> ItemSet {
+        group
+            .iter()
+            .map(Rucksack::items)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ItemSet, Rucksack};
+    use crate::{Error, Letters, PriorityScheme, Table};
+    use common::error::{Line, Spanned};
+
+    fn rucksack(text: &str) -> Rucksack {
+        Rucksack::parse(&Line::new(1, text), &Letters).unwrap()
+    }
+
+    #[test]
+    fn item_set() {
+        let mut set = ItemSet::EMPTY;
+        for priority in [1, 27, 52, 27] {
+            set.insert(priority);
+        }
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.first(), Some(1));
+        assert_eq!(set.items(&Letters), "aAZ");
+        assert!(ItemSet::EMPTY.is_empty());
+        assert_eq!(ItemSet::EMPTY.first(), None);
+    }
+
+    #[test]
+    fn common_items() {
+        let rucksack = rucksack("vJrwpWtwJgWrhcsFMMfFFhFp");
+
+        assert_eq!(rucksack.common_items().items(&Letters), "p");
+        assert_eq!(rucksack.items().len(), 14);
+    }
+
+    #[test]
+    fn common_item() {
+        assert_eq!(
+            rucksack("vJrwpWtwJgWrhcsFMMfFFhFp").common_item(&Letters),
+            Ok(16)
+        );
+        assert!(matches!(
+            rucksack("abcd").common_item(&Letters),
+            Err(Error::NoCommonItem(_))
+        ));
+        assert_eq!(
+            rucksack("abab")
+                .common_item(&Letters)
+                .unwrap_err()
+                .to_string(),
+            "the compartments of rucksack 'abab' have the items 'ab' in common"
+        );
+        assert_eq!(
+            rucksack("abcab")
+                .common_item(&Letters)
+                .unwrap_err()
+                .to_string(),
+            "rucksack 'abcab' has an odd number of items, 5"
+        );
+    }
+
+    #[test]
+    fn intersect_group() {
+        let group = [
+            rucksack("vJrwpWtwJgWrhcsFMMfFFhFp"),
+            rucksack("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"),
+            rucksack("PmmdzqPrVvPwwTWBwg"),
+        ];
+
+        assert_eq!(Rucksack::intersect_group(&group).items(&Letters), "r");
+        assert!(Rucksack::intersect_group(&[]).is_empty());
+    }
+
+    #[test]
+    fn invalid_item() {
+        let error = Rucksack::parse(&Line::new(3, "abc1ab"), &Letters).unwrap_err();
+
+        assert!(matches!(error, Error::InvalidItem(_)));
+        assert_eq!((error.span().line, error.span().column), (3, 4));
+        assert_eq!(
+            Rucksack::parse(&Line::new(1, "aéa"), &Letters)
+                .unwrap_err()
+                .span()
+                .text,
+            "é"
+        );
+    }
+
+    #[test]
+    fn priority_out_of_range() {
+        // '0' and '9' have priorities no `ItemSet` can hold
+        struct Digits;
+        impl PriorityScheme for Digits {
+            fn priority(&self, item: char) -> Option<u8> {
+                item.to_digit(10).map(|digit| digit as u8 * 10)
+            }
+
+            fn item(&self, priority: u8) -> Option<char> {
+                char::from_digit(priority as u32 / 10, 10)
+            }
+        }
+
+        assert!(Rucksack::parse(&Line::new(1, "1234"), &Digits).is_ok());
+        for text in ["1204", "1294"] {
+            let error = Rucksack::parse(&Line::new(1, text), &Digits).unwrap_err();
+            assert!(matches!(error, Error::InvalidItem(_)));
+            assert_eq!(error.span().column, 3);
+        }
+    }
+
+    #[test]
+    fn other_schemes() {
+        let greek = Table::parse(include_str!("../priorities/greek.toml")).unwrap();
+        let rucksack = Rucksack::parse(&Line::new(1, "αβγΩδεζΩ"), &greek).unwrap();
+
+        assert_eq!(rucksack.common_item(&greek), Ok(48));
+        assert_eq!(rucksack.items().items(&greek), "αβγδεζΩ");
+        assert!(Rucksack::parse(&Line::new(1, "αa"), &greek).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Error, PriorityScheme, Rucksack};
+use common::error::Line;
+use std::fmt::{Display, Formatter};
+
+// what kind of rucksack a line of the input is, only `WellFormed` ones can be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    WellFormed(char),
+    NoCommonItem,
+    CommonItems(String),
+    OddLength(usize),
+    InvalidItem { item: String, column: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub line: usize,
+    pub verdict: Verdict,
+}
+
+impl Verdict {
+    pub fn is_well_formed(&self) -> bool {
+        matches!(self, Verdict::WellFormed(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::WellFormed(item) => write!(f, "one common item '{item}'"),
+            Verdict::NoCommonItem => write!(f, "no common item"),
+            Verdict::CommonItems(items) => write!(f, "several common items '{items}'"),
+            Verdict::OddLength(len) => write!(f, "odd number of items, {len}"),
+            Verdict::InvalidItem { item, column } => {
+                write!(f, "invalid item '{item}' in column {column}")
+            }
+        }
+    }
+}
+
+// checks every line of `input` as a rucksack
+pub fn validate(input: &str, scheme: &dyn PriorityScheme) -> Vec<Check> {
+    Line::all(input)
+        .map(|line| {
+            let verdict = match Rucksack::parse(&line, scheme).and_then(|r| r.common_item(scheme)) {
+                Ok(priority) => Verdict::WellFormed(scheme.item(priority).unwrap_or_default()),
+                Err(Error::CommonItems { items, .. }) => Verdict::CommonItems(items),
+                Err(Error::OddLength(span)) => Verdict::OddLength(span.text.chars().count()),
+                Err(Error::InvalidItem(span)) => Verdict::InvalidItem {
+                    item: span.text,
+                    column: span.column,
+                },
+                Err(Error::NoCommonItem(_)) => Verdict::NoCommonItem,
+                // not checked for single rucksacks
+                Err(
+                    error @ (Error::NoBadge(_) | Error::Badges { .. } | Error::CannotRepack(_)),
+                ) => {
+                    unreachable!("{error}")
+                }
+            };
+
+            Check {
+                line: line.number,
+                verdict,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, Verdict};
+    use crate::Letters;
+
+    #[test]
+    fn verdicts() {
+        let verdicts: Vec<_> = validate(
+            "vJrwpWtwJgWrhcsFMMfFFhFp\nabcd\nabab\nabcab\nab1b\n",
+            &Letters,
+        )
+        .into_iter()
+        .map(|check| (check.line, check.verdict))
+        .collect();
+
+        assert_eq!(
+            verdicts,
+            vec![
+                (1, Verdict::WellFormed('p')),
+                (2, Verdict::NoCommonItem),
+                (3, Verdict::CommonItems("ab".to_string())),
+                (4, Verdict::OddLength(5)),
+                (
+                    5,
+                    Verdict::InvalidItem {
+                        item: "1".to_string(),
+                        column: 3
+                    }
+                ),
+            ]
+        );
+        assert_eq!(verdicts[4].1.to_string(), "invalid item '1' in column 3");
+    }
+}
@@ -0,0 +1,30 @@
+use common::error::{Span, Spanned};
+use std::num::ParseIntError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("expected two comma separated section ranges, got '{}'", .0.text)]
+    MissingRange(Span),
+    #[error("expected a section range like '2-4', got '{}'", .0.text)]
+    InvalidRange(Span),
+    #[error("section id '{}' is too large", .0.text)]
+    SectionTooLarge(Span),
+    #[error("invalid section id '{}'", .span.text)]
+    InvalidSection {
+        span: Span,
+        #[source]
+        source: ParseIntError,
+    },
+}
+
+impl Spanned for ParseError {
+    fn span(&self) -> &Span {
+        match self {
+            ParseError::MissingRange(span)
+            | ParseError::InvalidRange(span)
+            | ParseError::SectionTooLarge(span)
+            | ParseError::InvalidSection { span, .. } => span,
+        }
+    }
+}
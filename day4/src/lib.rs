@@ -0,0 +1,211 @@
+use anyhow::Result;
+use common::error::{Diagnostic, Line};
+use common::Solution;
+pub use error::ParseError;
+use log::{debug, trace};
+use std::collections::HashSet;
+use std::ops::Range;
+use std::str::FromStr;
+
+mod error;
+
+pub struct Day4;
+
+pub type Assignment = (Range<u32>, Range<u32>);
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Assignment>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_assignments(input).map_err(Diagnostic::from)?)
+    }
+
+    fn part1(assignments: &Self::Input) -> Result<Self::Output> {
+        Ok(overlap_check(assignments, complete_overlapp))
+    }
+
+    fn part2(assignments: &Self::Input) -> Result<Self::Output> {
+        Ok(overlap_check(assignments, partial_overlapp))
+    }
+}
+
+pub fn parse_assignments(input: &str) -> Result<Vec<Assignment>, ParseError> {
+    let mut assignments = vec![];
+    for line in Line::all(input) {
+        let (sections1, sections2) = line
+            .text
+            .split_once(',')
+            .ok_or_else(|| ParseError::MissingRange(line.whole()))?;
+
+        let assignment = (
+            parse_range(sections1, &line)?,
+            parse_range(sections2, &line)?,
+        );
+        trace!("line {}: {:?}", line.number, assignment);
+        assignments.push(assignment);
+    }
+    debug!("parsed {} assignments", assignments.len());
+
+    Ok(assignments)
+}
+
+pub fn overlap_check(
+    assignments: &[Assignment],
+    overlapp_check: fn(&HashSet<u32>, &HashSet<u32>) -> bool,
+) -> u32 {
+    let mut cnt = 0;
+    for (range1, range2) in assignments {
+        let sections1: HashSet<u32> = HashSet::from_iter(range1.clone());
+        let sections2: HashSet<u32> = HashSet::from_iter(range2.clone());
+
+        if overlapp_check(&sections1, &sections2) {
+            cnt += 1
+        }
+    }
+
+    cnt
+}
+
+// `range` has to be a sub slice of `line`
+pub fn parse_range(range: &str, line: &Line) -> Result<Range<u32>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::InvalidRange(line.span(range)))?;
+    let section = |id: &str| {
+        u32::from_str(id).map_err(|source| ParseError::InvalidSection {
+            span: line.span(id),
+            source,
+        })
+    };
+    let (start, end_id) = (section(start)?, section(end)?);
+    if start > end_id {
+        return Err(ParseError::InvalidRange(line.span(range)));
+    }
+
+    Ok(Range {
+        start,
+        // range is defined: [start;end[ ... but we want [start;end]
+        end: end_id
+            .checked_add(1)
+            .ok_or_else(|| ParseError::SectionTooLarge(line.span(end)))?,
+    })
+}
+
+pub fn complete_overlapp(sections1: &HashSet<u32>, sections2: &HashSet<u32>) -> bool {
+    sections1.is_superset(sections2) || sections2.is_superset(sections1)
+}
+
+pub fn partial_overlapp(sections1: &HashSet<u32>, sections2: &HashSet<u32>) -> bool {
+    sections1.intersection(sections2).count() > 0 || sections2.intersection(sections1).count() > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day4, ParseError};
+    use common::error::{Line, Spanned};
+    use common::Solution;
+    use proptest::prelude::*;
+    use std::ops::Range;
+
+    #[test]
+    fn parse_range() {
+        let range = super::parse_range("2-6", &Line::new(1, "2-6")).unwrap();
+
+        assert_eq!(range, Range { start: 2, end: 7 });
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = super::parse_assignments("2-4,6-8\n2-4;6-8\n").unwrap_err();
+        assert!(matches!(err, ParseError::MissingRange(_)));
+        assert_eq!(err.span().line, 2);
+
+        let err = super::parse_assignments("2-4,6-8\n2-4,68\n").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidRange(_)));
+        assert_eq!((err.span().column, err.span().text.as_str()), (5, "68"));
+
+        let err = super::parse_assignments("2-4,6-x8\n").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidSection { .. }));
+        assert_eq!((err.span().column, err.span().text.as_str()), (7, "x8"));
+        assert_eq!(err.to_string(), "invalid section id 'x8'");
+
+        let err = super::parse_assignments("6-2,3-4\n").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidRange(_)));
+        assert_eq!((err.span().column, err.span().text.as_str()), (1, "6-2"));
+
+        let err = super::parse_assignments("0-4294967295,1-2\n").unwrap_err();
+        assert!(matches!(err, ParseError::SectionTooLarge(_)));
+        assert_eq!(err.span().column, 3);
+    }
+
+    #[test]
+    fn part1() {
+        let assignments = Day4::parse(
+            r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+"#,
+        )
+        .unwrap();
+
+        let result = Day4::part1(&assignments).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn part2() {
+        let assignments = Day4::parse(
+            r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+"#,
+        )
+        .unwrap();
+
+        let result = Day4::part2(&assignments).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    fn section_range() -> impl Strategy<Value = (u32, u32)> {
+        (0..100u32, 0..100u32).prop_map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    // interval arithmetic on the inclusive bounds, independent of the section sets
+    fn contains((a, b): (u32, u32), (c, d): (u32, u32)) -> bool {
+        a <= c && d <= b
+    }
+
+    fn overlaps((a, b): (u32, u32), (c, d): (u32, u32)) -> bool {
+        a.max(c) <= b.min(d)
+    }
+
+    proptest! {
+        #[test]
+        fn overlaps_match_interval_oracle(
+            pairs in prop::collection::vec((section_range(), section_range()), 0..50)
+        ) {
+            let input: String = pairs
+                .iter()
+                .map(|((a, b), (c, d))| format!("{a}-{b},{c}-{d}\n"))
+                .collect();
+            let assignments = Day4::parse(&input).unwrap();
+
+            let ranges: Vec<_> = pairs.iter().map(|((a, b), (c, d))| (*a..b + 1, *c..d + 1)).collect();
+            prop_assert_eq!(&assignments, &ranges);
+
+            let complete = pairs.iter().filter(|(r1, r2)| contains(*r1, *r2) || contains(*r2, *r1)).count();
+            let partial = pairs.iter().filter(|(r1, r2)| overlaps(*r1, *r2)).count();
+            prop_assert_eq!(Day4::part1(&assignments).unwrap() as usize, complete);
+            prop_assert_eq!(Day4::part2(&assignments).unwrap() as usize, partial);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use common::error::{Span, Spanned};
+use std::num::ParseIntError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("expected a crate like '[A]' or an empty slot, got '{}'", .0.text)]
+    InvalidCrate(Span),
+    #[error("expected a command like 'move 3 from 8 to 2', got '{}'", .0.text)]
+    InvalidCommand(Span),
+    #[error("invalid number '{}'", .span.text)]
+    InvalidNumber {
+        span: Span,
+        #[source]
+        source: ParseIntError,
+    },
+    #[error("there is no stack {}, only stacks 1 to {stacks}", .span.text)]
+    UnknownStack { span: Span, stacks: usize },
+    #[error("expected a crate drawing, stack numbers or a move command, got '{}'", .0.text)]
+    UnexpectedLine(Span),
+}
+
+impl Spanned for ParseError {
+    fn span(&self) -> &Span {
+        match self {
+            ParseError::InvalidCrate(span)
+            | ParseError::InvalidCommand(span)
+            | ParseError::InvalidNumber { span, .. }
+            | ParseError::UnknownStack { span, .. }
+            | ParseError::UnexpectedLine(span) => span,
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use crate::CrateMoverType::CrateMover9000;
+use crate::CrateMoverType::CrateMover9001;
+use anyhow::{bail, Result};
+use common::error::{Diagnostic, Line};
+use common::Solution;
+pub use error::ParseError;
+use log::{debug, trace};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+mod error;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (Stacks, Program);
+    type Output = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input).map_err(Diagnostic::from)?)
+    }
+
+    fn part1((stacks, program): &Self::Input) -> Result<Self::Output> {
+        program.execute(&mut stacks.clone(), CrateMover9000)
+    }
+
+    fn part2((stacks, program): &Self::Input) -> Result<Self::Output> {
+        program.execute(&mut stacks.clone(), CrateMover9001)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stacks(Vec<Vec<char>>);
+
+impl Stacks {
+    fn new() -> Stacks {
+        Stacks(vec![])
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn resize(&mut self, len: usize) {
+        if self.len() < len {
+            self.0.resize(len, vec![]);
+        }
+    }
+
+    fn push(&mut self, line: &Line) -> Result<(), ParseError> {
+        // one crate is four characters long (including the separating space), the
+        // latter is at index 1; trailing empty slots might be missing:
+        // "[W] [B] [T] [F] [L] [T] [M] [F] [T]"
+        // "[T]             [P]     [J]"
+        //   ^   ^   ^   ^   ^   ^   ^   ^   ^
+        let num_of_chars_per_crate = 4;
+        let chars = line.text.char_indices().collect::<Vec<_>>();
+
+        for (i, slot) in chars.chunks(num_of_chars_per_crate).enumerate() {
+            if self.len() <= i {
+                self.0.push(vec![]);
+            }
+
+            let (start, _) = slot[0];
+            let end = slot.get(3).map(|(end, _)| *end).unwrap_or(line.text.len());
+            let current_crate = &line.text[start..end];
+
+            match current_crate.chars().collect::<Vec<_>>().as_slice() {
+                ['[', c, ']'] => self.0[i].insert(0, *c),
+                [' ', ' ', ' '] | [' ', ' '] | [' '] => {}
+                _ => return Err(ParseError::InvalidCrate(line.span(current_crate))),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+struct MoveCommand {
+    from: usize,
+    to: usize,
+    count: usize,
+}
+
+pub enum CrateMoverType {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+#[derive(Debug)]
+pub struct Program(Vec<MoveCommand>);
+
+impl Program {
+    fn new() -> Self {
+        Program(vec![])
+    }
+
+    fn add_command(&mut self, command: MoveCommand) {
+        self.0.resize(self.0.len() + 1, command);
+    }
+
+    pub fn execute(&self, stacks: &mut Stacks, mover: CrateMoverType) -> Result<String> {
+        let mut result = String::from("");
+        for command in &self.0 {
+            debug!("{command}");
+            let stack = &mut stacks.0[command.from - 1];
+            let length = stack.len();
+            if command.count > length {
+                bail!(
+                    "can't move {} crates from stack {}, it only holds {} crates",
+                    command.count,
+                    command.from,
+                    length
+                )
+            }
+            let crates: &mut Vec<char> = &mut stack.drain(length - command.count..).collect();
+
+            if let CrateMover9000 = mover {
+                crates.reverse();
+            }
+
+            stacks.0[command.to - 1].append(crates);
+            trace!("stacks: {:?}", stacks.0);
+        }
+        // empty stacks have no top crate
+        for stack in &stacks.0 {
+            result.extend(stack.last());
+        }
+
+        Ok(result)
+    }
+}
+
+impl MoveCommand {
+    // commands are like: "move 3 from 8 to 2"
+    fn parse(line: &Line, num_of_stacks: usize) -> Result<MoveCommand, ParseError> {
+        let number = |token: &str| {
+            usize::from_str(token).map_err(|source| ParseError::InvalidNumber {
+                span: line.span(token),
+                source,
+            })
+        };
+        let stack = |token: &str| match number(token)? {
+            n @ 1.. if n <= num_of_stacks => Ok(n),
+            _ => Err(ParseError::UnknownStack {
+                span: line.span(token),
+                stacks: num_of_stacks,
+            }),
+        };
+
+        match line.text.split(' ').collect::<Vec<&str>>().as_slice() {
+            ["move", count, "from", from, "to", to] => Ok(MoveCommand {
+                count: number(count)?,
+                from: stack(from)?,
+                to: stack(to)?,
+            }),
+            _ => Err(ParseError::InvalidCommand(line.whole())),
+        }
+    }
+}
+
+impl Display for MoveCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Stacks, Program), ParseError> {
+    let mut stacks = Stacks::new();
+    let mut program = Program::new();
+
+    for line in Line::all(input) {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+
+        if line.text.contains('[') {
+            stacks.push(&line)?;
+        } else if line.text.starts_with("move") {
+            program.add_command(MoveCommand::parse(&line, stacks.len())?);
+        } else if line.text.chars().all(|c| c == ' ' || c.is_ascii_digit()) {
+            // the stack numbers: " 1   2   3 "; trailing stacks without any crate
+            // only show up here if the crate lines are trimmed
+            stacks.resize(line.text.split_whitespace().count());
+        } else {
+            return Err(ParseError::UnexpectedLine(line.whole()));
+        }
+    }
+
+    debug!(
+        "parsed {} stacks and {} commands",
+        stacks.len(),
+        program.0.len()
+    );
+    Ok((stacks, program))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day5, MoveCommand, ParseError};
+    use common::error::{Line, Spanned};
+    use common::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn push_to_new_stack() {
+        let mut stacks = super::Stacks::new();
+
+        assert_eq!(stacks.len(), 0);
+
+        let result = &stacks.push(&Line::new(1, "    [D]    "));
+        assert!(result.is_ok());
+
+        assert_eq!(stacks.0[0].len(), 0);
+        assert_eq!(stacks.len(), 3);
+        assert_eq!(stacks.0[1][0], 'D');
+    }
+
+    #[test]
+    fn push_to_new_stack2() {
+        let mut stacks = super::Stacks::new();
+
+        assert_eq!(stacks.len(), 0);
+
+        let result = &stacks.push(&Line::new(1, "    [D]    "));
+        assert!(result.is_ok());
+        let result = &stacks.push(&Line::new(2, "[N] [C]    "));
+        assert!(result.is_ok());
+        let result = &stacks.push(&Line::new(3, "[Z] [M] [P]"));
+        assert!(result.is_ok());
+
+        assert_eq!(stacks.0[0].len(), 2);
+        assert_eq!(stacks.0[1].len(), 3);
+        assert_eq!(stacks.0[2].len(), 1);
+        assert_eq!(stacks.len(), 3);
+        assert_eq!(stacks.0[1][1], 'C');
+        assert_eq!(stacks.0[1][0], 'M');
+    }
+
+    #[test]
+    fn parse_move_command() {
+        let cmd = MoveCommand::parse(&Line::new(1, "move 3 from 8 to 12"), 12);
+
+        assert!(cmd.is_ok());
+        assert_eq!(
+            cmd.unwrap(),
+            MoveCommand {
+                from: 8,
+                to: 12,
+                count: 3
+            }
+        )
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input_result = super::parse_input(
+            r#"[T]             [P]     [J]
+[F]     [S]     [T]     [R]     [B]
+[V]     [M] [H] [S]     [F]     [R]
+[Z]     [P] [Q] [B]     [S] [W] [P]
+[C]     [Q] [R] [D] [Z] [N] [H] [Q]
+[W] [B] [T] [F] [L] [T] [M] [F] [T]
+[S] [R] [Z] [V] [G] [R] [Q] [N] [Z]
+[Q] [Q] [B] [D] [J] [W] [H] [R] [J]
+ 1   2   3   4   5   6   7   8   9
+
+move 3 from 8 to 2
+move 3 from 1 to 5
+move 3 from 1 to 4
+move 2 from 7 to 4
+move 3 from 7 to 4
+move 8 from 5 to 7
+move 2 from 1 to 8
+"#,
+        );
+
+        assert!(input_result.is_ok());
+
+        let (stacks, program) = input_result.unwrap();
+
+        assert_eq!(stacks.0[0].len(), 8);
+        assert_eq!(stacks.0[1].len(), 3);
+        assert_eq!(stacks.len(), 9);
+        assert_eq!(program.0.len(), 7)
+    }
+
+    const EXAMPLE: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"#;
+
+    #[test]
+    fn parts() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day5::part1(&input).unwrap(), "CMZ");
+        assert_eq!(Day5::part2(&input).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = super::parse_input("[A] (B)\n").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidCrate(_)));
+        assert_eq!((err.span().column, err.span().text.as_str()), (5, "(B)"));
+
+        let err = super::parse_input("[A] [B]\n\nmove 1 from 1\n").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidCommand(_)));
+        assert_eq!(err.span().line, 3);
+
+        let err = super::parse_input("[A] [B]\n\nmove x from 1 to 2\n").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidNumber { .. }));
+        assert_eq!((err.span().column, err.span().text.as_str()), (6, "x"));
+
+        let err = super::parse_input("[A] [B]\n\nmove 1 from 1 to 3\n").unwrap_err();
+        assert!(matches!(err, ParseError::UnknownStack { stacks: 2, .. }));
+        assert_eq!(err.span().column, 18);
+
+        let err = super::parse_input("[A] [B]\n 1   2\nstack 3\n").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedLine(_)));
+    }
+
+    #[test]
+    fn execute_too_many_crates() {
+        let input = Day5::parse("[A] [B]\n\nmove 2 from 1 to 2\n").unwrap();
+
+        assert!(Day5::part1(&input).is_err());
+    }
+
+    fn drawing(stacks: &[Vec<char>], trim: bool) -> String {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+        let mut drawing = String::new();
+
+        for level in (0..height).rev() {
+            let row = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            drawing += if trim { row.trim_end() } else { &row };
+            drawing += "\n";
+        }
+
+        let numbers = (1..=stacks.len()).map(|i| format!(" {i} "));
+        drawing + &numbers.collect::<Vec<_>>().join(" ") + "\n"
+    }
+
+    fn stacks_and_commands() -> impl Strategy<Value = (Vec<Vec<char>>, Vec<MoveCommand>)> {
+        prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 0..6),
+            1..12,
+        )
+        .prop_filter("no crates", |stacks| {
+            stacks.iter().any(|stack| !stack.is_empty())
+        })
+        .prop_flat_map(|stacks| {
+            let n = stacks.len();
+            let command = (0..50usize, 1..=n, 1..=n).prop_map(|(count, from, to)| MoveCommand {
+                count,
+                from,
+                to,
+            });
+            (Just(stacks), prop::collection::vec(command, 0..20))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn drawing_round_trip((stacks, commands) in stacks_and_commands(), trim: bool) {
+            let program: String = commands.iter().map(|command| format!("{command}\n")).collect();
+            let input = format!("{}\n{program}", drawing(&stacks, trim));
+
+            let (parsed, program) = super::parse_input(&input).unwrap();
+
+            prop_assert_eq!(parsed.0, stacks);
+            prop_assert_eq!(program.0, commands);
+        }
+
+        #[test]
+        fn move_command_round_trip((stacks, commands) in stacks_and_commands()) {
+            for command in commands {
+                let line = command.to_string();
+                let parsed = MoveCommand::parse(&Line::new(1, &line), stacks.len()).unwrap();
+                prop_assert_eq!(parsed, command);
+            }
+        }
+    }
+}
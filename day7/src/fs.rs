@@ -1,13 +1,13 @@
 use std::borrow::Borrow;
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct File {
     pub name: String,
     pub size: usize,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Dir {
     pub(crate) name: String,
     pub(crate) sub_dirs: Vec<Dir>,
@@ -67,7 +67,7 @@ impl<'a> IntoIterator for &'a Dir {
 }
 
 impl Dir {
-    pub fn iter(&self) -> DirIterator {
+    pub fn iter(&self) -> DirIterator<'_> {
         DirIterator::new(self)
     }
 
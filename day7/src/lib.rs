@@ -1,2 +1,60 @@
+use anyhow::{bail, Context, Result};
+use common::Solution;
+use fs::Dir;
+
 pub mod fs;
 pub mod session_parser;
+
+const TOTAL_DISK_SPACE: usize = 70_000_000;
+const NECESSARY_FREE_SPACE: usize = 30_000_000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Dir;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        session_parser::parse_session(input)
+    }
+
+    fn part1(fs: &Self::Input) -> Result<Self::Output> {
+        Ok(fs.total_sum_of_all_dirs_smaller_than(100_000))
+    }
+
+    fn part2(fs: &Self::Input) -> Result<Self::Output> {
+        let Some(free_space) = TOTAL_DISK_SPACE.checked_sub(fs.total_size()) else {
+            bail!(
+                "the files take {} bytes, more than the disk has ({TOTAL_DISK_SPACE})",
+                fs.total_size()
+            )
+        };
+        // nothing has to be deleted if there is enough free space already
+        let Some(space_to_free) = NECESSARY_FREE_SPACE
+            .checked_sub(free_space)
+            .filter(|space| *space > 0)
+        else {
+            return Ok(0);
+        };
+
+        fs.smallest_dir_greater_than(space_to_free)
+            .context("no directory is big enough to free the necessary space")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day7;
+    use common::Solution;
+
+    #[test]
+    fn part2_small_sessions() {
+        let fs = Day7::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+        assert_eq!(Day7::part2(&fs).unwrap(), 0);
+
+        let fs = Day7::parse("$ cd /\n$ ls\n70000001 a\n").unwrap();
+        assert!(Day7::part2(&fs).is_err());
+    }
+}
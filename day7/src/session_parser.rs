@@ -1,5 +1,6 @@
 use crate::fs::{Dir, File};
 use anyhow::{bail, Context, Result};
+use log::{debug, trace};
 use std::str::Split;
 
 struct CdCommand {
@@ -44,16 +45,23 @@ fn parse_session_part(line_iterator: &mut Split<char>, current_dir: &mut Dir) ->
                 continue;
             }
             match parse_line(line) {
-                Ok(ParseResult::Command(Command::Ls)) => {}
-                Ok(ParseResult::File(file)) => current_dir.files.push(File {
-                    name: file.name,
-                    size: file.size,
-                }),
+                Ok(ParseResult::Command(Command::Ls)) => {
+                    trace!("ls in '{}'", current_dir.name);
+                }
+                Ok(ParseResult::File(file)) => {
+                    trace!("file '{}' ({} bytes)", file.name, file.size);
+                    current_dir.files.push(File {
+                        name: file.name,
+                        size: file.size,
+                    })
+                }
                 Ok(ParseResult::Dirname(dirname)) => {
+                    trace!("dir '{dirname}'");
                     let dir = Dir::new(&dirname);
                     current_dir.sub_dirs.push(dir);
                 }
                 Ok(ParseResult::Command(Command::Cd(cd_command))) => {
+                    debug!("cd '{}' from '{}'", cd_command.target, current_dir.name);
                     if cd_command.target == ".." {
                         return Ok(());
                     }
@@ -88,7 +96,10 @@ pub fn parse_session(session: &str) -> Result<Dir> {
 
 #[cfg(test)]
 mod tests {
+    use crate::fs::{Dir, File};
     use crate::session_parser::{parse_line, ParseResult};
+    use proptest::prelude::*;
+    use std::fmt::Write;
 
     const SESSION: &str = r#"$ cd /
 $ ls
@@ -202,4 +213,90 @@ $ ls
         assert!(size_of_dir_to_delete.is_some());
         assert_eq!(size_of_dir_to_delete.unwrap(), 24933642);
     }
+
+    fn files() -> impl Strategy<Value = Vec<File>> {
+        prop::collection::btree_map("[a-z]{1,8}(\\.[a-z]{3})?", 1..1_000_000usize, 0..4).prop_map(
+            |files| {
+                files
+                    .into_iter()
+                    .map(|(name, size)| File { name, size })
+                    .collect()
+            },
+        )
+    }
+
+    // directory names are assigned by the parent, so they are unique per directory
+    fn dir() -> impl Strategy<Value = Dir> {
+        let leaf = files().prop_map(|files| Dir {
+            files,
+            ..Dir::new("")
+        });
+
+        leaf.prop_recursive(4, 64, 4, |inner| {
+            (
+                files(),
+                prop::collection::btree_map("[a-z]{1,8}", inner, 0..4),
+            )
+                .prop_map(|(files, sub_dirs)| Dir {
+                    name: String::new(),
+                    files,
+                    sub_dirs: sub_dirs
+                        .into_iter()
+                        .map(|(name, dir)| Dir { name, ..dir })
+                        .collect(),
+                })
+        })
+    }
+
+    // the session a user exploring `dir` depth first would have produced
+    fn session(dir: &Dir, session: &mut String) {
+        writeln!(session, "$ cd {}\n$ ls", dir.name).unwrap();
+        for sub_dir in &dir.sub_dirs {
+            writeln!(session, "dir {}", sub_dir.name).unwrap();
+        }
+        for file in &dir.files {
+            writeln!(session, "{} {}", file.size, file.name).unwrap();
+        }
+        for sub_dir in &dir.sub_dirs {
+            self::session(sub_dir, session);
+            writeln!(session, "$ cd ..").unwrap();
+        }
+    }
+
+    // sizes of `dir` and all its descendants, computed recursively
+    fn dir_sizes(dir: &Dir, sizes: &mut Vec<usize>) -> usize {
+        let size = dir.files.iter().map(|file| file.size).sum::<usize>()
+            + dir
+                .sub_dirs
+                .iter()
+                .map(|sub_dir| dir_sizes(sub_dir, sizes))
+                .sum::<usize>();
+        sizes.push(size);
+        size
+    }
+
+    proptest! {
+        #[test]
+        fn session_round_trip(root in dir()) {
+            let root = Dir { name: "/".to_string(), ..root };
+            let mut input = String::new();
+            session(&root, &mut input);
+
+            let fs = super::parse_session(&input).unwrap();
+            let mut expected = Dir::new_fs();
+            expected.sub_dirs = vec![root];
+            prop_assert_eq!(&fs, &expected);
+
+            let mut sizes = vec![];
+            prop_assert_eq!(fs.total_size(), dir_sizes(&expected.sub_dirs[0], &mut sizes));
+            prop_assert_eq!(
+                fs.total_sum_of_all_dirs_smaller_than(100_000),
+                sizes.iter().filter(|size| **size < 100_000).sum::<usize>()
+            );
+            prop_assert_eq!(
+                fs.smallest_dir_greater_than(1_000_000),
+                sizes.iter().copied().filter(|size| *size > 1_000_000).min()
+            );
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::forrest::Direction::{Down, Left, Right, Up};
+use log::debug;
 use std::str::FromStr;
 
 #[derive(Default, Debug)]
@@ -122,6 +123,10 @@ impl FromStr for Forrest {
         forrest.width = rows.clone().next().unwrap_or_default().len();
 
         forrest.tree_matrix = rows.collect::<Vec<&str>>().as_slice().join("").into();
+        debug!(
+            "parsed a forrest of {}x{} trees",
+            forrest.width, forrest.height
+        );
 
         Ok(forrest)
     }
@@ -170,14 +175,14 @@ impl Forrest {
         false
     }
 
-    fn coordinate_iterator(&self) -> CoordinateIterator {
+    fn coordinate_iterator(&self) -> CoordinateIterator<'_> {
         CoordinateIterator {
             forrest: self,
             current_coordinate: None,
         }
     }
 
-    fn trees(&self, start_coordinate: Coordinate, direction: Direction) -> ForrestIterator {
+    fn trees(&self, start_coordinate: Coordinate, direction: Direction) -> ForrestIterator<'_> {
         ForrestIterator {
             forrest: self,
             current_coordinate: start_coordinate,
@@ -196,6 +201,7 @@ impl Forrest {
 mod tests {
     use crate::forrest::Direction::{Down, Left, Right, Up};
     use crate::forrest::Forrest;
+    use proptest::prelude::*;
 
     #[test]
     fn forrest_iterator() {
@@ -206,10 +212,7 @@ mnopqr
 "#;
         let start_coordinate = (2, 1).into();
         let forrest: Forrest = INPUT.parse().unwrap();
-        assert_eq!(
-            *forrest.get_tree_height(start_coordinate).unwrap(),
-            'X' as u8
-        );
+        assert_eq!(*forrest.get_tree_height(start_coordinate).unwrap(), b'X');
         assert_eq!(
             forrest
                 .trees(start_coordinate, Up)
@@ -266,4 +269,53 @@ mnopqr
         assert_eq!(forrest.scenic_score((2, 3).into()), 8);
         assert_eq!(forrest.highest_scenic_score(), 8);
     }
+
+    fn grid() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(0..10u8, width), height)
+        })
+    }
+
+    // the trees seen from (x, y) looking up, right, down and left, nearest first
+    fn lines_of_sight(grid: &[Vec<u8>], x: usize, y: usize) -> [Vec<u8>; 4] {
+        [
+            (0..y).rev().map(|y| grid[y][x]).collect(),
+            grid[y][x + 1..].to_vec(),
+            (y + 1..grid.len()).map(|y| grid[y][x]).collect(),
+            grid[y][..x].iter().rev().copied().collect(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(grid in grid()) {
+            let input: String = grid
+                .iter()
+                .map(|row| row.iter().map(|height| (b'0' + height) as char).collect::<String>() + "\n")
+                .collect();
+            let forrest: Forrest = input.parse().unwrap();
+
+            let mut visible = 0;
+            let mut highest_score = 0;
+            for (y, row) in grid.iter().enumerate() {
+                for (x, height) in row.iter().enumerate() {
+                    let lines = lines_of_sight(&grid, x, y);
+
+                    visible += lines.iter().any(|line| line.iter().all(|tree| tree < height)) as usize;
+
+                    let score = lines
+                        .iter()
+                        .map(|line| match line.iter().position(|tree| tree >= height) {
+                            Some(blocked) => blocked + 1,
+                            None => line.len(),
+                        })
+                        .product();
+                    highest_score = highest_score.max(score);
+                }
+            }
+
+            prop_assert_eq!(forrest.num_of_visible_trees(), visible);
+            prop_assert_eq!(forrest.highest_scenic_score(), highest_score);
+        }
+    }
 }
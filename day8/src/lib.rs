@@ -1 +1,26 @@
+use anyhow::Result;
+use common::Solution;
+use forrest::Forrest;
+
 pub mod forrest;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Forrest;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(forrest: &Self::Input) -> Result<Self::Output> {
+        Ok(forrest.num_of_visible_trees())
+    }
+
+    fn part2(forrest: &Self::Input) -> Result<Self::Output> {
+        Ok(forrest.highest_scenic_score())
+    }
+}